@@ -0,0 +1,83 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Compares decoding by inverting the permutation with decoding by
+//! regenerating names day by day until one matches.
+//!
+//! Run with `cargo bench --bench decode`.
+
+use chrono::NaiveDate;
+use holotype::config::Config;
+use holotype::data::Morphemes;
+use holotype::generator::{decode_name, generate_name};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn brute_force_decode(
+    name: &str,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Option<(NaiveDate, u32)> {
+    let mut date = NaiveDate::from_ymd_opt(config.year_start, 1, 1)?;
+    let last = NaiveDate::from_ymd_opt(config.year_end, 12, 31)?;
+
+    while date <= last {
+        for number in config.number_min..=config.number_max {
+            if generate_name(date, number, salt, morphemes, config).unwrap() == name {
+                return Some((date, number));
+            }
+        }
+        date = date.succ_opt()?;
+    }
+
+    None
+}
+
+fn main() {
+    let morphemes = Morphemes::new();
+    let config = Config::default();
+
+    // Brute force is linear in the distance from the start of the range, so
+    // a date one year in is already enough to show the gap.
+    let target = NaiveDate::from_ymd_opt(config.year_start + 1, 1, 1).unwrap();
+    let name = generate_name(target, 1, "", &morphemes, &config).unwrap();
+
+    let start = Instant::now();
+    black_box(brute_force_decode(&name, "", &morphemes, &config));
+    let brute_force = start.elapsed();
+
+    let names: Vec<String> = (0..100)
+        .map(|i| {
+            let date = NaiveDate::from_ymd_opt(2000 + i, 1 + (i as u32 % 12), 1).unwrap();
+            generate_name(date, 1 + i as u32 % 99, "", &morphemes, &config).unwrap()
+        })
+        .collect();
+
+    let start = Instant::now();
+    for name in &names {
+        black_box(decode_name(name, "", &morphemes, &config));
+    }
+    let inverse = start.elapsed() / names.len() as u32;
+
+    println!("brute force ({}): {:?}", target, brute_force);
+    println!("inverse (mean of {} names): {:?}", names.len(), inverse);
+    println!(
+        "speedup: {:.0}x",
+        brute_force.as_secs_f64() / inverse.max(Duration::from_nanos(1)).as_secs_f64()
+    );
+}
@@ -0,0 +1,142 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrings that names must not contain.
+
+use crate::data::{BLOCKLIST, WHOLE_WORDS};
+use std::path::Path;
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Blocklist {
+    entries: Vec<String>,
+    /// Entries that only match a word they spell whole.
+    words: Vec<String>,
+}
+
+/// The list in [`BLOCKLIST`], for schemes with `builtin_blocklist`; with
+/// `whole_words`, the entries in [`WHOLE_WORDS`] only match whole words.
+pub fn builtin(whole_words: bool) -> &'static Blocklist {
+    static LIST: OnceLock<Blocklist> = OnceLock::new();
+    static WORD_LIST: OnceLock<Blocklist> = OnceLock::new();
+    if !whole_words {
+        return LIST.get_or_init(|| Blocklist::new(BLOCKLIST));
+    }
+    WORD_LIST.get_or_init(|| {
+        let entries: Vec<&str> = BLOCKLIST
+            .iter()
+            .copied()
+            .filter(|entry| !WHOLE_WORDS.contains(entry))
+            .collect();
+        Blocklist::new(&entries).with_whole_words(WHOLE_WORDS)
+    })
+}
+
+/// Lowercases `text` and folds spellings that read alike, y as i and ph as
+/// f, so that "Phuk" and "fuk" match the same entry.
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase().replace('y', "i").replace("ph", "f")
+}
+
+impl Blocklist {
+    pub fn new<S: AsRef<str>>(entries: &[S]) -> Self {
+        let mut normalized: Vec<String> = Vec::new();
+        for entry in entries {
+            let entry = normalize(entry.as_ref().trim());
+            if !entry.is_empty() && !normalized.contains(&entry) {
+                normalized.push(entry);
+            }
+        }
+        Self {
+            entries: normalized,
+            words: Vec::new(),
+        }
+    }
+
+    /// Adds `words`, which only match a word they spell whole.
+    pub fn with_whole_words<S: AsRef<str>>(mut self, words: &[S]) -> Self {
+        self.words = Self::new(words).entries;
+        self
+    }
+
+    /// Parses a blocklist file: one entry per line, `#` starting a comment.
+    pub fn parse(text: &str) -> Self {
+        let entries: Vec<&str> = text
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .collect();
+        Self::new(&entries)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read blocklist {}: {}", path.display(), e))?;
+        Ok(Self::parse(&text))
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// The first entry found in `word`, if any.
+    pub fn find(&self, word: &str) -> Option<&str> {
+        let word = normalize(word);
+        self.entries
+            .iter()
+            .find(|entry| word.contains(entry.as_str()))
+            .or_else(|| self.words.iter().find(|entry| **entry == word))
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization() {
+        let list = Blocklist::new(&["fuk", "Shit"]);
+
+        assert_eq!(list.find("Phukosus"), Some("fuk"));
+        assert_eq!(list.find("SHYTUS"), Some("shit"));
+        assert_eq!(list.find("Neomorphus"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let list = Blocklist::parse("# ours\nbad\n\n  worse  # comment\nBAD\n");
+        assert_eq!(list.entries(), ["bad", "worse"]);
+    }
+
+    #[test]
+    fn test_builtin_entries_are_normalized() {
+        for entry in BLOCKLIST.iter().chain(WHOLE_WORDS) {
+            assert_eq!(&normalize(entry), entry);
+        }
+    }
+
+    #[test]
+    fn test_whole_words() {
+        for word in ["Computatus", "puteus", "Deputatis"] {
+            assert!(builtin(false).find(word).is_some(), "{}", word);
+            assert_eq!(builtin(true).find(word), None, "{}", word);
+        }
+        assert_eq!(builtin(true).find("Puta"), Some("puta"));
+        assert_eq!(builtin(true).find("Putainus"), Some("putain"));
+        assert!(WHOLE_WORDS.iter().all(|entry| BLOCKLIST.contains(entry)));
+    }
+}
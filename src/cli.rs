@@ -15,28 +15,223 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
 
+/// Without a subcommand, holotype takes a number to name, or a name to
+/// decode with `-x`, as it did before subcommands existed.
 #[derive(Parser)]
 #[command(name = "holotype")]
 #[command(about = "Generate reproducible names for musical projects and patches")]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Ordinal number for generation (or name for extraction with -x)
+    #[arg(conflicts_with_all = ["time", "now"])]
     pub value: Option<String>,
 
-    /// Index
-    #[arg(short, long, value_name = "NUMBER")]
+    /// Ordinal number, as the first argument
+    #[arg(short, long, value_name = "NUMBER", hide = true)]
+    #[arg(conflicts_with_all = ["time", "now"])]
     pub index: Option<u32>,
 
+    /// Extract date and number from name
+    #[arg(short = 'x', long, conflicts_with_all = ["time", "now"])]
+    pub extract: bool,
+
+    #[command(flatten)]
+    pub moment: MomentArgs,
+
+    /// Also list the names passed over before the one generated, and which
+    /// quality rules they broke
+    #[arg(long, conflicts_with = "extract")]
+    pub why_rejected: bool,
+
+    #[command(flatten)]
+    pub names: NameArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Name a numbered work or a moment
+    Generate(GenerateArgs),
+    /// Find the date and number, or moment, a name stands for
+    Decode(DecodeArgs),
+    /// Name a range of numbers on one or more days, one name per line
+    Batch(BatchArgs),
+    /// Break a name into its parts and say what each one means
+    Explain(DecodeArgs),
+    /// Count the names a scheme and its options provide, and how many the
+    /// ranges use
+    Stats(StatsArgs),
+    /// Check that this build reproduces every golden test vector
+    Verify,
+    /// Check morpheme tables for malformed entries and for entries that no
+    /// name can use
+    LintData {
+        /// Morpheme pack to check instead of the built-in tables
+        #[arg(long, value_name = "FILE")]
+        morphemes: Option<PathBuf>,
+        /// Check the roots of a theme instead of the anatomical ones
+        #[arg(long, value_name = "THEME", conflicts_with = "morphemes")]
+        theme: Option<String>,
+        /// Scheme whose quality filter judges the genera (defaults to the
+        /// newest)
+        #[arg(short, long, value_name = "ID")]
+        scheme: Option<String>,
+    },
+    /// Train a pronounceability model from a word list, one name per line
+    TrainModel {
+        /// Word list to learn spellings from
+        words: PathBuf,
+        /// Length of the letter sequences the model counts
+        #[arg(long, default_value_t = 3)]
+        order: usize,
+        /// File to write the model to (defaults to standard output)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Ordinal number of the work
+    #[arg(conflicts_with_all = ["time", "now"])]
+    pub number: Option<u32>,
+
+    #[command(flatten)]
+    pub moment: MomentArgs,
+
+    /// Also list the names passed over before the one generated, and which
+    /// quality rules they broke
+    #[arg(long)]
+    pub why_rejected: bool,
+
+    #[command(flatten)]
+    pub names: NameArgs,
+}
+
+#[derive(Args)]
+pub struct DecodeArgs {
+    /// Name to decode or explain
+    pub name: String,
+
+    #[command(flatten)]
+    pub names: NameArgs,
+}
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// Numbers to name (FIRST-LAST, e.g. 1-12, or a single number)
+    #[arg(value_name = "RANGE")]
+    pub range: String,
+
+    /// First date (YYYY-MM-DD format, defaults to today)
+    #[arg(short, long, value_name = "DATE")]
+    pub date: Option<String>,
+
+    /// Number of consecutive days to name the numbers on
+    #[arg(long, value_name = "COUNT", default_value_t = 1)]
+    pub days: u32,
+
+    #[command(flatten)]
+    pub names: NameArgs,
+}
+
+#[derive(Args)]
+pub struct StatsArgs {
+    #[command(flatten)]
+    pub names: NameArgs,
+}
+
+/// When a work was made.
+#[derive(Args)]
+pub struct MomentArgs {
     /// Date (YYYY-MM-DD format, defaults to today)
     #[arg(short, long, value_name = "DATE")]
     pub date: Option<String>,
 
+    /// Time of day (HH:MM) to name instead of a number
+    #[arg(long, value_name = "TIME")]
+    pub time: Option<String>,
+
+    /// Name the current date and time instead of a number
+    #[arg(long, conflicts_with_all = ["date", "time"])]
+    pub now: bool,
+}
+
+/// Everything that decides which name a work gets, and so has to be given
+/// alike to generate and to decode.
+#[derive(Args)]
+pub struct NameArgs {
     /// Type
     #[arg(short = 't', long = "type", value_name = "TYPE")]
     pub salt: Option<String>,
 
-    /// Extract date and number from name
-    #[arg(short = 'x', long)]
-    pub extract: bool,
+    /// Date range to use instead of the scheme's (START-END, e.g. 1900-2199)
+    #[arg(short, long, value_name = "RANGE")]
+    pub years: Option<String>,
+
+    /// Number range to use instead of the scheme's (MIN-MAX, e.g. 1-999)
+    #[arg(short, long, value_name = "RANGE")]
+    pub numbers: Option<String>,
+
+    /// Year counted from when packing dates (defaults to the scheme's own if
+    /// the range contains it, otherwise to the first year of the range)
+    #[arg(short, long, value_name = "YEAR", requires = "years")]
+    pub epoch: Option<i32>,
+
+    /// Add a subspecies epithet, for far more names than binomials allow
+    #[arg(long)]
+    pub trinomial: bool,
+
+    /// Never mix Greek and Latin morphemes within a genus, for about half as
+    /// many names
+    #[arg(long)]
+    pub pure: bool,
+
+    /// Prefix categories to build names from, e.g. colour,environment;
+    /// CATEGORY=WEIGHT gives one a larger share of the names than those
+    /// weighted lower, and TYPE=LIST;... picks them per type, with weights
+    /// written CATEGORY:WEIGHT
+    #[arg(long, value_name = "LIST")]
+    pub categories: Option<String>,
+
+    /// Key file for a private namespace (32 hex digits)
+    #[arg(short, long, value_name = "FILE")]
+    pub key: Option<PathBuf>,
+
+    /// Pronounceability model file to judge names with instead of the
+    /// scheme's own (see `train-model`)
+    #[arg(short, long, value_name = "FILE")]
+    pub model: Option<PathBuf>,
+
+    /// File of substrings, one per line, that no word of a name may contain,
+    /// checked alongside the built-in blocklist
+    #[arg(short, long, value_name = "FILE")]
+    pub blocklist: Option<PathBuf>,
+
+    /// Move on to the next number or time slot while the name could be
+    /// mistaken for one already in the ledger FILE, record the name
+    /// generated there, and print it after the number or time it stands for
+    #[arg(long, value_name = "FILE")]
+    pub distinct: Option<PathBuf>,
+
+    /// Morpheme pack, a TOML or JSON file, to build names from instead of
+    /// the built-in tables
+    #[arg(long, value_name = "FILE")]
+    pub morphemes: Option<PathBuf>,
+
+    /// Build names from roots on a theme instead of anatomical ones: marine,
+    /// botanical, mineral, celestial or acoustic; TYPE=THEME,... picks one
+    /// per type
+    #[arg(long, value_name = "THEME", conflicts_with = "morphemes")]
+    pub theme: Option<String>,
+
+    /// Naming scheme (generation defaults to the newest; extraction tries
+    /// every scheme except "v1", which has to be asked for)
+    #[arg(short, long, value_name = "ID")]
+    pub scheme: Option<String>,
 }
@@ -15,26 +15,90 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::blocklist::Blocklist;
+use crate::data::{self, Category, Pack, Theme};
+use crate::ngram::NgramModel;
+use crate::pronounceability::Model;
+use std::sync::Arc;
+
+pub const MIN_YEAR: i32 = 1;
+pub const MAX_YEAR: i32 = 9999;
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Config {
+    /// Year whose 1 January is day zero of the date packing. Names only
+    /// depend on the epoch and the range size, so widening the range around
+    /// a fixed epoch keeps the first-choice names of the days it held.
+    pub epoch_year: i32,
     pub year_start: i32,
     pub year_end: i32,
     pub number_min: u32,
     pub number_max: u32,
+    /// Numbers are packed in blocks of this many per day, so that raising
+    /// `number_max` adds blocks without moving the numbers already covered.
+    pub numbers_per_block: u32,
+    /// Width of the time slots that timed names stand for.
+    pub minutes_per_slot: u32,
+    /// Where the quality filter's later tries for a tuple go.
+    pub layout: Layout,
+    /// Add a subspecies epithet to every name, multiplying the number of
+    /// names by the size of the subspecies table.
+    pub trinomials: bool,
+    /// Inflect epithets to the gender of the genus suffix, e.g. "robusta"
+    /// after a genus in -a instead of "robustus".
+    pub gender_agreement: bool,
+    /// Only end roots in suffixes of their own origin or shared by Greek and
+    /// Latin, joined with the root's connector rather than the prefix's.
+    pub match_origins: bool,
+    /// Build every genus from morphemes of one origin: roots of the
+    /// prefix's, and suffixes of both.
+    pub pure_origins: bool,
+    /// Weight of each prefix category, in the order of [`Category::ALL`].
+    /// A category keeps a share of its names in proportion to its weight
+    /// over the largest, so those weighted 0 get none. `None` keeps every
+    /// name.
+    pub categories: Option<[u32; Category::ALL.len()]>,
+    /// Also hold genera to the phonotactic rules: Latin endings, no banned
+    /// clusters and no more than `max_consonant_cluster` consonants in a row.
+    pub enforce_phonotactics: bool,
     pub max_consonant_cluster: usize,
+    pub pronounceability: Model,
     pub min_pronounceability_score: f32,
     pub max_genus_length: usize,
+    /// Reject names with a word containing an entry of the built-in
+    /// blocklist.
+    pub builtin_blocklist: bool,
+    /// Only match the built-in entries in [`crate::data::WHOLE_WORDS`]
+    /// against whole words.
+    pub whole_word_blocklist: bool,
+    /// A blocklist of the user's own, checked alongside the built-in one.
+    pub blocklist: Option<Arc<Blocklist>>,
 }
 
 impl Config {
     pub const fn default() -> Self {
         Self {
+            epoch_year: 2000,
             year_start: 2000,
             year_end: 2099,
             number_min: 1,
-            number_max: 99,
+            number_max: 999,
+            numbers_per_block: 99,
+            minutes_per_slot: 3,
+            layout: Layout::Strided { attempts: 6 },
+            trinomials: false,
+            gender_agreement: true,
+            match_origins: true,
+            pure_origins: false,
+            categories: None,
+            enforce_phonotactics: true,
             max_consonant_cluster: 3,
-            min_pronounceability_score: 0.3,
+            pronounceability: Model::Ngram,
+            min_pronounceability_score: 0.5,
             max_genus_length: 18,
+            builtin_blocklist: true,
+            whole_word_blocklist: true,
+            blocklist: None,
         }
     }
 
@@ -42,9 +106,35 @@ impl Config {
         if self.year_start >= self.year_end {
             return Err("year_start must be less than year_end".to_string());
         }
+        if self.year_start < MIN_YEAR || self.year_end > MAX_YEAR {
+            return Err(format!(
+                "Years must lie between {} and {}",
+                MIN_YEAR, MAX_YEAR
+            ));
+        }
+        if !(self.year_start..=self.year_end).contains(&self.epoch_year) {
+            return Err("epoch_year must lie between year_start and year_end".to_string());
+        }
         if self.number_min >= self.number_max {
             return Err("number_min must be less than number_max".to_string());
         }
+        if self.numbers_per_block == 0 {
+            return Err("numbers_per_block must be at least 1".to_string());
+        }
+        if self.minutes_per_slot == 0 || 24 * 60 % self.minutes_per_slot != 0 {
+            return Err("minutes_per_slot must divide a day (1440 minutes)".to_string());
+        }
+        if matches!(self.layout, Layout::Strided { attempts } if attempts < 2) {
+            return Err(
+                "A strided layout needs at least 2 tries, to retry rejected names".to_string(),
+            );
+        }
+        if self
+            .categories
+            .is_some_and(|weights| weights.iter().all(|&w| w == 0))
+        {
+            return Err("At least one category must have a weight".to_string());
+        }
         if self.max_consonant_cluster < 2 {
             return Err("max_consonant_cluster must be at least 2".to_string());
         }
@@ -55,6 +145,211 @@ impl Config {
     }
 }
 
+/// How the tries of the quality filter are laid out over the name space.
+/// Try `a` for the tuple packed as `x` permutes `a * stride + x`, so tries
+/// never overlap with each other or with another tuple's first try, and the
+/// filter cannot make two tuples share a name.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Layout {
+    /// The stride is the size of the tuple domain, and there are as many
+    /// tries as fit in the name space. Widening a range moves every try but
+    /// the first, renaming the tuples that needed one.
+    Domain,
+    /// The name space is cut into `attempts` strides of equal size, which no
+    /// range changes. Binomials name the first pairs as far as their stride
+    /// reaches and trinomials name the rest, as well as every moment.
+    Strided { attempts: u32 },
+}
+
+/// Settings chosen on the command line in place of a scheme's own.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Overrides {
+    pub years: Option<(i32, i32)>,
+    pub epoch: Option<i32>,
+    pub numbers: Option<(u32, u32)>,
+    pub trinomials: bool,
+    pub pure_origins: bool,
+    pub categories: Option<[u32; Category::ALL.len()]>,
+    pub model: Option<Arc<NgramModel>>,
+    pub blocklist: Option<Arc<Blocklist>>,
+    pub pack: Option<Arc<Pack>>,
+    pub theme: Option<&'static Theme>,
+}
+
+impl Overrides {
+    /// Parses ranges written as `START-END`, e.g. `1900-2199`.
+    pub fn parse(
+        years: Option<&str>,
+        epoch: Option<i32>,
+        numbers: Option<&str>,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            years: years.map(|text| parse_range(text, "year")).transpose()?,
+            epoch,
+            numbers: numbers
+                .map(|text| parse_range(text, "number"))
+                .transpose()?,
+            trinomials: false,
+            pure_origins: false,
+            categories: None,
+            model: None,
+            blocklist: None,
+            pack: None,
+            theme: None,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Applies the overrides to `config`. Without an explicit epoch the config
+    /// keeps its own when the years still contain it, so the names of the
+    /// days it already covered stay the same.
+    pub fn apply(&self, config: &Config) -> Result<Config, String> {
+        let mut config = config.clone();
+
+        if let Some((start, end)) = self.years {
+            config.year_start = start;
+            config.year_end = end;
+            if !(start..=end).contains(&config.epoch_year) {
+                config.epoch_year = start;
+            }
+        }
+        if let Some(epoch) = self.epoch {
+            config.epoch_year = epoch;
+        }
+        if let Some((min, max)) = self.numbers {
+            config.number_min = min;
+            config.number_max = max;
+        }
+        config.trinomials |= self.trinomials;
+        config.pure_origins |= self.pure_origins;
+        if let Some(categories) = self.categories {
+            config.categories = Some(categories);
+        }
+        if let Some(model) = &self.model {
+            config.pronounceability = Model::Trained(Arc::clone(model));
+        }
+        if let Some(blocklist) = &self.blocklist {
+            config.blocklist = Some(Arc::clone(blocklist));
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Parses a range written as `START-END`, e.g. `1-999`.
+pub fn parse_range<T: std::str::FromStr>(text: &str, what: &str) -> Result<(T, T), String> {
+    text.split_once('-')
+        .and_then(|(start, end)| Some((start.parse().ok()?, end.parse().ok()?)))
+        .ok_or_else(|| format!("Invalid {} range: {} (expected START-END)", what, text))
+}
+
+/// Parses category weights written as `colour=3,environment` or
+/// `colour:3,environment`, where a category without a weight has 1 and one
+/// left out has none.
+pub fn parse_categories(text: &str) -> Result<[u32; Category::ALL.len()], String> {
+    let mut weights = [0; Category::ALL.len()];
+    let mut given = Vec::new();
+
+    for item in text.split(',') {
+        let (name, weight) = match item.split_once(['=', ':']) {
+            Some((name, weight)) => (
+                name,
+                weight
+                    .parse()
+                    .map_err(|_| format!("Invalid weight: {}", item))?,
+            ),
+            None => (item, 1),
+        };
+        let category = Category::from_name(name.trim()).ok_or_else(|| {
+            let known: Vec<&str> = Category::ALL.iter().map(|c| c.name()).collect();
+            format!("Unknown category: {} (known: {})", name, known.join(", "))
+        })?;
+
+        if given.contains(&category) {
+            return Err(format!("Category {} given twice", name));
+        }
+        given.push(category);
+        weights[category as usize] = weight;
+    }
+
+    Ok(weights)
+}
+
+/// Picks the category weights for `salt` from `text`: weights for every
+/// type, or weights per type written as `Ambient=environment:2,colour;
+/// Drums=form,size`, where a type left out keeps every category.
+pub fn parse_categories_for(
+    text: &str,
+    salt: &str,
+) -> Result<Option<[u32; Category::ALL.len()]>, String> {
+    let first = text.split([',', ';']).next().unwrap_or_default();
+    let per_type = match first.split_once('=') {
+        Some((name, _)) => Category::from_name(name.trim()).is_none(),
+        None => text.contains(';'),
+    };
+    if !per_type {
+        return parse_categories(text).map(Some);
+    }
+
+    let mut chosen = None;
+    let mut given = Vec::new();
+    for item in text.split(';') {
+        let (salt_given, list) = item
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid categories: {} (expected TYPE=LIST)", item))?;
+        let weights = parse_categories(list)?;
+
+        if given.contains(&salt_given) {
+            return Err(format!("Categories for {} given twice", salt_given));
+        }
+        given.push(salt_given);
+        if salt_given == salt {
+            chosen = Some(weights);
+        }
+    }
+
+    Ok(chosen)
+}
+
+/// Picks the theme for `salt` from `text`: a theme for every type, or themes
+/// per type written as `Beetle=mineral,Song=acoustic`, where a type left out
+/// keeps the anatomical roots.
+pub fn parse_themes(text: &str, salt: &str) -> Result<Option<&'static Theme>, String> {
+    if !text.contains('=') {
+        return find_theme(text.trim()).map(Some);
+    }
+
+    let mut chosen = None;
+    let mut given = Vec::new();
+    for item in text.split(',') {
+        let (salt_given, id) = item
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid theme: {} (expected TYPE=THEME)", item))?;
+        let theme = find_theme(id.trim())?;
+
+        if given.contains(&salt_given) {
+            return Err(format!("Theme for {} given twice", salt_given));
+        }
+        given.push(salt_given);
+        if salt_given == salt {
+            chosen = Some(theme);
+        }
+    }
+
+    Ok(chosen)
+}
+
+pub fn find_theme(id: &str) -> Result<&'static Theme, String> {
+    data::find_theme(id).ok_or_else(|| {
+        let known: Vec<&str> = data::THEMES.iter().map(|theme| theme.id).collect();
+        format!("Unknown theme: {} (known: {})", id, known.join(", "))
+    })
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self::default()
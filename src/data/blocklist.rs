@@ -0,0 +1,86 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// Substrings no name should contain, matched after normalization (see
+// `crate::blocklist::normalize`). Entries short enough to turn up in honest
+// Latin, such as "con", "anal", "fag" (fagus) or "pede" (pedes), are left out.
+// Schemes that check the list include it in their fingerprint, so editing it
+// takes a new scheme.
+pub const BLOCKLIST: &[&str] = &[
+    // English
+    "fuck",
+    "shit",
+    "cunt",
+    "piss",
+    "cock",
+    "dick",
+    "slut",
+    "whore",
+    "porn",
+    "turd",
+    "nigg",
+    "rapist",
+    "wank",
+    "twat",
+    "bitch",
+    "boob",
+    "analing",
+    "nazi",
+    "hitler",
+    // French
+    "merde",
+    "putain",
+    "pute",
+    "salop",
+    "couill",
+    "encul",
+    "connard",
+    "conass",
+    "bordel",
+    "chiass",
+    "nique",
+    // German
+    "scheis",
+    "arsch",
+    "fotze",
+    "wichs",
+    "fick",
+    "kack",
+    "hure",
+    "titte",
+    "schlamp",
+    "spast",
+    "neger",
+    // Spanish
+    "puta",
+    "mierd",
+    "pendej",
+    "cojon",
+    "chinga",
+    "maricon",
+    // Italian
+    "cazz",
+    "coglion",
+    "puttan",
+    "stronz",
+    "minchia",
+    "vaffancul",
+];
+
+// Entries of `BLOCKLIST` that also make up honest Latin, as in computatus or
+// puteus. From v9 they only block a word they spell whole.
+pub const WHOLE_WORDS: &[&str] = &["pute", "puta"];
@@ -0,0 +1,34 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::morphemes::{Root, Suffix};
+use super::{GENUS_SUFFIXES, ROOTS, THEMES};
+
+/// The built-in root spelled `text`, from the anatomical table or a theme,
+/// which gives roots listed by spelling alone their origin and gloss.
+pub fn builtin_root(text: &str) -> Option<&'static Root<'static>> {
+    ROOTS
+        .iter()
+        .chain(THEMES.iter().flat_map(|theme| theme.roots))
+        .find(|root| root.text == text)
+}
+
+/// The built-in genus suffix spelled `text`, which gives suffixes listed by
+/// spelling alone their origin and gloss.
+pub fn builtin_suffix(text: &str) -> Option<&'static Suffix<'static>> {
+    GENUS_SUFFIXES.iter().find(|suffix| suffix.text == text)
+}
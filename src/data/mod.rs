@@ -15,12 +15,22 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+mod blocklist;
+mod etymology;
 mod morphemes;
+mod pack;
 mod prefixes;
 mod roots;
 mod suffixes;
+mod themes;
 
-pub use morphemes::{Category, Morpheme, Morphemes, Origin, SpeciesDescriptor};
+pub use blocklist::{BLOCKLIST, WHOLE_WORDS};
+pub use etymology::{builtin_root, builtin_suffix};
+pub use morphemes::{
+    Category, Declension, Gender, Morpheme, Morphemes, Origin, Root, SpeciesDescriptor, Suffix,
+};
+pub use pack::Pack;
 pub use prefixes::PREFIXES;
 pub use roots::ROOTS;
-pub use suffixes::{GENUS_SUFFIXES, SPECIES_DESCRIPTORS};
+pub use suffixes::{GENUS_SUFFIXES, SPECIES_DESCRIPTORS, SUBSPECIES_DESCRIPTORS};
+pub use themes::{find_theme, Theme, THEMES};
@@ -33,39 +33,221 @@ pub enum Category {
     Quality,
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Morpheme {
-    pub text: &'static str,
+impl Category {
+    pub const ALL: [Category; 8] = [
+        Category::Size,
+        Category::Colour,
+        Category::Position,
+        Category::Time,
+        Category::Number,
+        Category::Form,
+        Category::Environment,
+        Category::Quality,
+    ];
+
+    /// Name used in packs and on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::Size => "size",
+            Category::Colour => "colour",
+            Category::Position => "position",
+            Category::Time => "time",
+            Category::Number => "number",
+            Category::Form => "form",
+            Category::Environment => "environment",
+            Category::Quality => "quality",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Morpheme<'a> {
+    pub text: &'a str,
+    /// English meaning, for explaining names; empty if unknown.
+    pub gloss: &'a str,
     pub origin: Origin,
     pub category: Category,
 }
 
-pub struct Morphemes {
-    pub prefixes: &'static [Morpheme],
-    pub roots: &'static [&'static str],
-    pub genus_suffixes: &'static [&'static str],
-    pub species_descriptors: &'static [SpeciesDescriptor],
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Root<'a> {
+    pub text: &'a str,
+    /// English meaning, for explaining names; empty if unknown.
+    pub gloss: &'a str,
+    /// Schemes that match origins join roots to suffixes by it, so it is
+    /// frozen like the text. `None` for pack roots of unknown origin, which
+    /// take their prefix's.
+    pub origin: Option<Origin>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Suffix<'a> {
+    pub text: &'a str,
+    /// English sense of what a word with the ending names, for explaining
+    /// names; empty if unknown. Most endings only make a noun of the stem,
+    /// as in "one" for -us.
+    pub gloss: &'a str,
+    /// Schemes that match origins only join a suffix to roots of its
+    /// origin, so it is frozen like the text. `None` for the endings both
+    /// languages use.
+    pub origin: Option<Origin>,
+}
+
+/// The tables names are built from: the built-in ones, or those lent out
+/// by a [`super::Pack`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Morphemes<'a> {
+    pub prefixes: Vec<Morpheme<'a>>,
+    pub roots: Vec<Root<'a>>,
+    pub genus_suffixes: Vec<Suffix<'a>>,
+    pub species_descriptors: Vec<SpeciesDescriptor<'a>>,
+    pub subspecies_descriptors: Vec<SpeciesDescriptor<'a>>,
+    /// Content hash of tables loaded from a pack, which sets their names
+    /// apart from those of the built-in tables; `None` for the latter.
+    pub pack_hash: Option<u64>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+impl Gender {
+    pub const ALL: [Gender; 3] = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+
+    /// Grammatical gender of a genus ending in `suffix`.
+    pub fn of_suffix(suffix: &str) -> Self {
+        match suffix {
+            "a" | "e" | "is" | "ix" => Gender::Feminine,
+            "um" | "on" | "ma" | "en" => Gender::Neuter,
+            _ => Gender::Masculine,
+        }
+    }
+}
+
+/// How an adjectival epithet changes with the gender of its genus. Texts
+/// are stored in the masculine.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Declension {
+    /// robustus, robusta, robustum
+    UsAUm,
+    /// pulcher, pulchra, pulchrum
+    ErRaRum,
+    /// gracilis, gracilis, gracile
+    IsIsE,
+    /// major, major, majus
+    OrOrUs,
+    /// One form for every gender: audax, elegans, and nouns such as rupicola
+    Invariant,
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct SpeciesDescriptor {
-    pub text: &'static str,
+impl Declension {
+    /// Ending of the masculine form, which the stored text must have.
+    pub fn ending(self) -> &'static str {
+        match self {
+            Declension::UsAUm => "us",
+            Declension::ErRaRum => "er",
+            Declension::IsIsE => "is",
+            Declension::OrOrUs => "or",
+            Declension::Invariant => "",
+        }
+    }
+
+    pub fn inflect(self, text: &str, gender: Gender) -> String {
+        let (ending, feminine, neuter) = match self {
+            Declension::UsAUm => ("us", "a", "um"),
+            Declension::ErRaRum => ("er", "ra", "rum"),
+            Declension::IsIsE => ("is", "is", "e"),
+            Declension::OrOrUs => ("or", "or", "us"),
+            Declension::Invariant => return text.to_string(),
+        };
+
+        let Some(stem) = text.strip_suffix(ending) else {
+            return text.to_string();
+        };
+        match gender {
+            Gender::Masculine => text.to_string(),
+            Gender::Feminine => format!("{}{}", stem, feminine),
+            Gender::Neuter => format!("{}{}", stem, neuter),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpeciesDescriptor<'a> {
+    pub text: &'a str,
+    /// English meaning, for explaining names; empty if unknown.
+    pub gloss: &'a str,
     pub category: Option<Category>,
+    pub declension: Declension,
 }
 
-impl Morphemes {
+impl Morphemes<'static> {
     pub fn new() -> Self {
         Self {
-            prefixes: super::PREFIXES,
-            roots: super::ROOTS,
-            genus_suffixes: super::GENUS_SUFFIXES,
-            species_descriptors: super::SPECIES_DESCRIPTORS,
+            prefixes: super::PREFIXES.to_vec(),
+            roots: super::ROOTS.to_vec(),
+            genus_suffixes: super::GENUS_SUFFIXES.to_vec(),
+            species_descriptors: super::SPECIES_DESCRIPTORS.to_vec(),
+            subspecies_descriptors: super::SUBSPECIES_DESCRIPTORS.to_vec(),
+            pack_hash: None,
         }
     }
 }
 
-impl Default for Morphemes {
+impl Default for Morphemes<'static> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflect() {
+        let cases = [
+            (
+                Declension::UsAUm,
+                "robustus",
+                ["robustus", "robusta", "robustum"],
+            ),
+            (
+                Declension::ErRaRum,
+                "pulcher",
+                ["pulcher", "pulchra", "pulchrum"],
+            ),
+            (
+                Declension::IsIsE,
+                "vulgaris",
+                ["vulgaris", "vulgaris", "vulgare"],
+            ),
+            (Declension::OrOrUs, "major", ["major", "major", "majus"]),
+            (Declension::Invariant, "velox", ["velox", "velox", "velox"]),
+        ];
+
+        for (declension, text, forms) in cases {
+            for (gender, form) in Gender::ALL.into_iter().zip(forms) {
+                assert_eq!(declension.inflect(text, gender), form);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gender_of_suffix() {
+        assert_eq!(Gender::of_suffix("us"), Gender::Masculine);
+        assert_eq!(Gender::of_suffix("a"), Gender::Feminine);
+        assert_eq!(Gender::of_suffix("is"), Gender::Feminine);
+        assert_eq!(Gender::of_suffix("um"), Gender::Neuter);
+        assert_eq!(Gender::of_suffix("ma"), Gender::Neuter);
+    }
+}
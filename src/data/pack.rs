@@ -0,0 +1,568 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Morpheme tables loaded from a pack file instead of the built-in ones.
+//!
+//! A pack is a TOML or JSON document with the same tables as `data/`:
+//!
+//! ```toml
+//! roots = ["cephal", "ocul"]
+//! genus_suffixes = ["us", "a", "um"]
+//!
+//! [[prefixes]]
+//! text = "Macro"
+//! origin = "greek"
+//! category = "size"
+//!
+//! [[species_descriptors]]
+//! text = "robustus"
+//! category = "size"
+//! declension = "us-a-um"
+//! ```
+//!
+//! Descriptors without a category suit every prefix, and those without a
+//! declension never change. Prefixes and descriptors may also give a
+//! `gloss`, their meaning in English, which `explain` shows; glosses do not
+//! change names. `subspecies_descriptors`, listed like species
+//! descriptors, are only needed for trinomials.
+//!
+//! Roots may also be listed as tables, to give their origin and gloss:
+//!
+//! ```toml
+//! [[roots]]
+//! text = "cephal"
+//! gloss = "head"
+//! origin = "greek"
+//! ```
+//!
+//! A root listed by spelling alone, or without an origin or gloss, takes
+//! them from the built-in root of the same spelling, if there is one; a
+//! root of unknown origin joins its prefix's. Genus suffixes may likewise
+//! be `[[genus_suffixes]]` tables with a `text`, `gloss` and `origin`, and
+//! take what they leave out from the built-in suffix; a suffix of unknown
+//! origin joins any root.
+
+use super::etymology::{builtin_root, builtin_suffix};
+use super::morphemes::{
+    Category, Declension, Morpheme, Morphemes, Origin, Root, SpeciesDescriptor, Suffix,
+};
+use crate::lint;
+use crate::scheme::{fnv, FNV_OFFSET};
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackFile {
+    prefixes: Vec<PackPrefix>,
+    roots: Vec<PackRoot>,
+    genus_suffixes: Vec<PackSuffix>,
+    species_descriptors: Vec<PackDescriptor>,
+    #[serde(default)]
+    subspecies_descriptors: Vec<PackDescriptor>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackPrefix {
+    text: String,
+    #[serde(default)]
+    gloss: String,
+    origin: String,
+    category: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PackRoot {
+    Text(String),
+    Entry(PackRootEntry),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackRootEntry {
+    text: String,
+    gloss: Option<String>,
+    origin: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PackSuffix {
+    Text(String),
+    Entry(PackSuffixEntry),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackSuffixEntry {
+    text: String,
+    gloss: Option<String>,
+    origin: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackDescriptor {
+    text: String,
+    #[serde(default)]
+    gloss: String,
+    category: Option<String>,
+    declension: Option<String>,
+}
+
+/// Morpheme tables read from a pack, which own their text and lend it out
+/// as [`Morphemes`].
+#[derive(Debug, PartialEq)]
+pub struct Pack {
+    prefixes: Vec<Prefix>,
+    roots: Vec<OwnedRoot>,
+    genus_suffixes: Vec<OwnedSuffix>,
+    species_descriptors: Vec<Descriptor>,
+    subspecies_descriptors: Vec<Descriptor>,
+    hash: u64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Prefix {
+    text: String,
+    gloss: String,
+    origin: Origin,
+    category: Category,
+}
+
+#[derive(Debug, PartialEq)]
+struct OwnedRoot {
+    text: String,
+    gloss: String,
+    origin: Option<Origin>,
+}
+
+#[derive(Debug, PartialEq)]
+struct OwnedSuffix {
+    text: String,
+    gloss: String,
+    origin: Option<Origin>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Descriptor {
+    text: String,
+    gloss: String,
+    category: Option<Category>,
+    declension: Declension,
+}
+
+const DECLENSIONS: &[(&str, Declension)] = &[
+    ("us-a-um", Declension::UsAUm),
+    ("er-ra-rum", Declension::ErRaRum),
+    ("is-is-e", Declension::IsIsE),
+    ("or-or-us", Declension::OrOrUs),
+    ("invariant", Declension::Invariant),
+];
+
+impl Pack {
+    /// Loads a pack, read as JSON if the file name ends in `.json` and as
+    /// TOML otherwise.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        Self::read(path, Self::parse)
+    }
+
+    /// Like [`Pack::from_file`], but only refuses packs it cannot make
+    /// tables of, so that `lint-data` can list everything wrong with them.
+    pub fn from_file_unchecked(path: &Path) -> Result<Self, String> {
+        Self::read(path, Self::parse_unchecked)
+    }
+
+    fn read(path: &Path, parse: fn(&str, bool) -> Result<Self, String>) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read morphemes {}: {}", path.display(), e))?;
+        let json = path.extension().is_some_and(|ext| ext == "json");
+
+        parse(&text, json).map_err(|e| format!("Invalid morphemes {}: {}", path.display(), e))
+    }
+
+    /// Parses a pack and refuses it if [`lint::lint_tables`] finds an entry
+    /// that is malformed.
+    pub fn parse(text: &str, json: bool) -> Result<Self, String> {
+        let pack = Self::parse_unchecked(text, json)?;
+        match lint::lint_tables(&pack.morphemes())
+            .into_iter()
+            .find(|finding| finding.problem.is_malformed())
+        {
+            Some(finding) => Err(finding.to_string()),
+            None => Ok(pack),
+        }
+    }
+
+    pub fn parse_unchecked(text: &str, json: bool) -> Result<Self, String> {
+        let file: PackFile = if json {
+            serde_json::from_str(text).map_err(|e| e.to_string())?
+        } else {
+            toml::from_str(text).map_err(|e| e.to_string())?
+        };
+
+        let prefixes = file
+            .prefixes
+            .into_iter()
+            .map(|prefix| {
+                Ok(Prefix {
+                    origin: origin(&prefix.origin)?,
+                    category: category(&prefix.category)?,
+                    text: prefix.text,
+                    gloss: prefix.gloss,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let roots = file
+            .roots
+            .into_iter()
+            .map(|root| {
+                let (text, gloss, origin) = match root {
+                    PackRoot::Text(text) => (text, None, None),
+                    PackRoot::Entry(entry) => (entry.text, entry.gloss, entry.origin),
+                };
+                let builtin = builtin_root(&text);
+                Ok(OwnedRoot {
+                    gloss: gloss
+                        .or_else(|| builtin.map(|root| root.gloss.to_string()))
+                        .unwrap_or_default(),
+                    origin: match origin {
+                        Some(name) => Some(self::origin(&name)?),
+                        None => builtin.and_then(|root| root.origin),
+                    },
+                    text,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let genus_suffixes = file
+            .genus_suffixes
+            .into_iter()
+            .map(|suffix| {
+                let (text, gloss, origin) = match suffix {
+                    PackSuffix::Text(text) => (text, None, None),
+                    PackSuffix::Entry(entry) => (entry.text, entry.gloss, entry.origin),
+                };
+                let builtin = builtin_suffix(&text);
+                Ok(OwnedSuffix {
+                    gloss: gloss
+                        .or_else(|| builtin.map(|suffix| suffix.gloss.to_string()))
+                        .unwrap_or_default(),
+                    origin: match origin {
+                        Some(name) => Some(self::origin(&name)?),
+                        None => builtin.and_then(|suffix| suffix.origin),
+                    },
+                    text,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let species_descriptors = descriptors(file.species_descriptors)?;
+        let subspecies_descriptors = descriptors(file.subspecies_descriptors)?;
+
+        for (table, len) in [
+            ("prefixes", prefixes.len()),
+            ("roots", roots.len()),
+            ("genus_suffixes", genus_suffixes.len()),
+            ("species_descriptors", species_descriptors.len()),
+        ] {
+            if len == 0 {
+                return Err(format!("Table {} is empty", table));
+            }
+        }
+        if !genus_suffixes
+            .iter()
+            .any(|s| !matches!(s.text.as_str(), "yx" | "ix" | "ax"))
+        {
+            return Err("Genus suffixes -yx, -ix and -ax are never used alone".to_string());
+        }
+
+        let mut pack = Self {
+            prefixes,
+            roots,
+            genus_suffixes,
+            species_descriptors,
+            subspecies_descriptors,
+            hash: 0,
+        };
+        pack.hash = content_hash(&pack.morphemes());
+        Ok(pack)
+    }
+
+    /// The tables of the pack, borrowing its text.
+    pub fn morphemes(&self) -> Morphemes<'_> {
+        let prefixes = self.prefixes.iter().map(|prefix| Morpheme {
+            text: &prefix.text,
+            gloss: &prefix.gloss,
+            origin: prefix.origin,
+            category: prefix.category,
+        });
+
+        Morphemes {
+            prefixes: prefixes.collect(),
+            roots: self
+                .roots
+                .iter()
+                .map(|root| Root {
+                    text: &root.text,
+                    gloss: &root.gloss,
+                    origin: root.origin,
+                })
+                .collect(),
+            genus_suffixes: self
+                .genus_suffixes
+                .iter()
+                .map(|suffix| Suffix {
+                    text: &suffix.text,
+                    gloss: &suffix.gloss,
+                    origin: suffix.origin,
+                })
+                .collect(),
+            species_descriptors: lend(&self.species_descriptors),
+            subspecies_descriptors: lend(&self.subspecies_descriptors),
+            pack_hash: Some(self.hash),
+        }
+    }
+}
+
+fn lend(descriptors: &[Descriptor]) -> Vec<SpeciesDescriptor<'_>> {
+    descriptors
+        .iter()
+        .map(|descriptor| SpeciesDescriptor {
+            text: &descriptor.text,
+            gloss: &descriptor.gloss,
+            category: descriptor.category,
+            declension: descriptor.declension,
+        })
+        .collect()
+}
+
+fn origin(name: &str) -> Result<Origin, String> {
+    match name {
+        "greek" => Ok(Origin::Greek),
+        "latin" => Ok(Origin::Latin),
+        other => Err(format!("Unknown origin: {}", other)),
+    }
+}
+
+fn category(name: &str) -> Result<Category, String> {
+    Category::from_name(name).ok_or_else(|| format!("Unknown category: {}", name))
+}
+
+fn descriptors(list: Vec<PackDescriptor>) -> Result<Vec<Descriptor>, String> {
+    list.into_iter()
+        .map(|descriptor| {
+            let declension = match descriptor.declension.as_deref() {
+                None => Declension::Invariant,
+                Some(name) => DECLENSIONS
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|&(_, declension)| declension)
+                    .ok_or_else(|| format!("Unknown declension: {}", name))?,
+            };
+
+            Ok(Descriptor {
+                category: descriptor.category.as_deref().map(category).transpose()?,
+                declension,
+                text: descriptor.text,
+                gloss: descriptor.gloss,
+            })
+        })
+        .collect()
+}
+
+/// FNV-1a over every field of the tables but the glosses, so that packs
+/// with the same content get the same names however they are written down
+/// or annotated.
+fn content_hash(morphemes: &Morphemes) -> u64 {
+    let mut hash = FNV_OFFSET;
+    let mut feed = |bytes: &[u8]| fnv(&mut hash, bytes);
+
+    for prefix in &morphemes.prefixes {
+        feed(prefix.text.as_bytes());
+        feed(&[prefix.origin as u8, prefix.category as u8]);
+    }
+    feed(b"roots");
+    for root in &morphemes.roots {
+        feed(root.text.as_bytes());
+        feed(&[root.origin.map_or(0xff, |o| o as u8)]);
+    }
+    feed(b"suffixes");
+    for suffix in &morphemes.genus_suffixes {
+        feed(suffix.text.as_bytes());
+        feed(&[suffix.origin.map_or(0xff, |o| o as u8)]);
+    }
+    for (table, descriptors) in [
+        ("species", &morphemes.species_descriptors),
+        ("subspecies", &morphemes.subspecies_descriptors),
+    ] {
+        feed(table.as_bytes());
+        for descriptor in descriptors {
+            feed(descriptor.text.as_bytes());
+            feed(&[
+                descriptor.category.map_or(0xff, |c| c as u8),
+                descriptor.declension as u8,
+            ]);
+        }
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACK: &str = r#"
+roots = ["cephal", "ocul"]
+genus_suffixes = ["us", "a"]
+
+[[prefixes]]
+text = "Macro"
+origin = "greek"
+category = "size"
+
+[[species_descriptors]]
+text = "robustus"
+gloss = "sturdy"
+category = "size"
+declension = "us-a-um"
+
+[[species_descriptors]]
+text = "velox"
+"#;
+
+    #[test]
+    fn test_parse_pack() {
+        let pack = Pack::parse(PACK, false).unwrap();
+        let morphemes = pack.morphemes();
+
+        let roots: Vec<&str> = morphemes.roots.iter().map(|root| root.text).collect();
+        assert_eq!(roots, ["cephal", "ocul"]);
+        assert_eq!(morphemes.roots[0].origin, Some(Origin::Greek));
+        assert_eq!(morphemes.roots[1].gloss, "eye");
+        assert_eq!(morphemes.prefixes[0].origin, Origin::Greek);
+        assert_eq!(
+            morphemes.species_descriptors[0].category,
+            Some(Category::Size)
+        );
+        assert_eq!(
+            morphemes.species_descriptors[1].declension,
+            Declension::Invariant
+        );
+        assert_eq!(morphemes.species_descriptors[0].gloss, "sturdy");
+        assert_eq!(morphemes.species_descriptors[1].gloss, "");
+        assert!(morphemes.subspecies_descriptors.is_empty());
+        assert!(morphemes.pack_hash.is_some());
+    }
+
+    #[test]
+    fn test_formats_share_a_hash() {
+        let json = r#"{
+            "prefixes": [{"text": "Macro", "origin": "greek", "category": "size"}],
+            "roots": ["cephal", "ocul"],
+            "genus_suffixes": ["us", "a"],
+            "species_descriptors": [
+                {"text": "robustus", "category": "size", "declension": "us-a-um"},
+                {"text": "velox"}
+            ]
+        }"#;
+        let toml = Pack::parse(PACK, false).unwrap();
+        let json = Pack::parse(json, true).unwrap();
+        let changed = Pack::parse(&PACK.replace("ocul", "ot"), false).unwrap();
+
+        assert_eq!(toml.hash, json.hash);
+        assert_ne!(toml.hash, changed.hash);
+    }
+
+    #[test]
+    fn test_root_tables() {
+        let tables = PACK.replace("roots = [\"cephal\", \"ocul\"]\n", "")
+            + "\n[[roots]]\ntext = \"cephal\"\n\n[[roots]]\ntext = \"ocul\"\n";
+        let latin = tables.replace(
+            "text = \"cephal\"",
+            "text = \"cephal\"\ngloss = \"pate\"\norigin = \"latin\"",
+        );
+        let unknown = PACK.replace("\"ocul\"", "\"zorb\"");
+
+        let plain = Pack::parse(PACK, false).unwrap();
+        let tables = Pack::parse(&tables, false).unwrap();
+        let latin = Pack::parse(&latin, false).unwrap();
+        let unknown = Pack::parse(&unknown, false).unwrap();
+
+        assert_eq!(tables, plain);
+        let cephal = latin.morphemes().roots[0];
+        assert_eq!((cephal.gloss, cephal.origin), ("pate", Some(Origin::Latin)));
+        assert_ne!(latin.hash, plain.hash);
+        let zorb = unknown.morphemes().roots[1];
+        assert_eq!((zorb.gloss, zorb.origin), ("", None));
+    }
+
+    #[test]
+    fn test_suffix_tables() {
+        let suffixes = |list| PACK.replace("[\"us\", \"a\"]", list);
+        let tables = suffixes("[{ text = \"us\" }, { text = \"a\" }]");
+        let latin = suffixes("[\"us\", { text = \"a\", gloss = \"she\", origin = \"latin\" }]");
+        let unknown = suffixes("[\"os\", \"ox\"]");
+
+        let plain = Pack::parse(PACK, false).unwrap();
+        let tables = Pack::parse(&tables, false).unwrap();
+        let latin = Pack::parse(&latin, false).unwrap();
+        let unknown = Pack::parse(&unknown, false).unwrap();
+
+        assert_eq!(tables, plain);
+        let a = latin.morphemes().genus_suffixes[1];
+        assert_eq!((a.gloss, a.origin), ("she", Some(Origin::Latin)));
+        assert_ne!(latin.hash, plain.hash);
+        let suffixes: Vec<_> = unknown
+            .morphemes()
+            .genus_suffixes
+            .iter()
+            .map(|suffix| (suffix.gloss, suffix.origin))
+            .collect();
+        assert_eq!(suffixes, [("one", Some(Origin::Greek)), ("", None)]);
+    }
+
+    #[test]
+    fn test_validation() {
+        let invalid = [
+            PACK.replace("\"ocul\"", "\"Ocul\""),
+            PACK.replace("\"ocul\"", "\"cephal\""),
+            PACK.replace("greek", "coptic"),
+            PACK.replace(
+                "roots = [\"cephal\", \"ocul\"]",
+                "roots = [{ text = \"cephal\", origin = \"coptic\" }]",
+            ),
+            PACK.replace(
+                "[\"us\", \"a\"]",
+                "[{ text = \"us\", origin = \"coptic\" }]",
+            ),
+            PACK.replace(
+                "category = \"size\"\ndeclension",
+                "category = \"mood\"\ndeclension",
+            ),
+            PACK.replace("robustus", "robusta"),
+            PACK.replace("[\"us\", \"a\"]", "[]"),
+            PACK.replace("[\"us\", \"a\"]", "[\"ix\"]"),
+            PACK.replace("text = \"velox\"", "text = \"velox\"\ncolour = \"red\""),
+        ];
+
+        for text in &invalid {
+            assert!(Pack::parse(text, false).is_err(), "{}", text);
+        }
+    }
+}
@@ -20,751 +20,901 @@ use super::morphemes::{Category, Morpheme, Origin};
 pub const PREFIXES: &[Morpheme] = &[
     Morpheme {
         text: "Macro",
+        gloss: "large",
         origin: Origin::Greek,
         category: Category::Size,
     },
     Morpheme {
         text: "Micro",
+        gloss: "small",
         origin: Origin::Greek,
         category: Category::Size,
     },
     Morpheme {
         text: "Mega",
+        gloss: "great",
         origin: Origin::Greek,
         category: Category::Size,
     },
     Morpheme {
         text: "Mini",
+        gloss: "least",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Magni",
+        gloss: "great",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Parvi",
+        gloss: "small",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Maxi",
+        gloss: "greatest",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Grandi",
+        gloss: "grand",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Brachy",
+        gloss: "short",
         origin: Origin::Greek,
         category: Category::Size,
     },
     Morpheme {
         text: "Lepto",
+        gloss: "slender",
         origin: Origin::Greek,
         category: Category::Size,
     },
     Morpheme {
         text: "Longi",
+        gloss: "long",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Brevi",
+        gloss: "short",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Lati",
+        gloss: "broad",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Angusti",
+        gloss: "narrow",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Alti",
+        gloss: "high",
         origin: Origin::Latin,
         category: Category::Size,
     },
     Morpheme {
         text: "Bathy",
+        gloss: "deep",
         origin: Origin::Greek,
         category: Category::Size,
     },
     Morpheme {
         text: "Leuco",
+        gloss: "white",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Melano",
+        gloss: "black",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Xantho",
+        gloss: "yellow",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Chloro",
+        gloss: "green",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Rhodo",
+        gloss: "rose-red",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Cyano",
+        gloss: "blue",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Porphyro",
+        gloss: "purple",
         origin: Origin::Greek,
         category: Category::Colour,
     },
     Morpheme {
         text: "Albo",
+        gloss: "white",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Nigri",
+        gloss: "black",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Rubi",
+        gloss: "red",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Flavi",
+        gloss: "golden-yellow",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Fulvi",
+        gloss: "tawny",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Griseo",
+        gloss: "grey",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Roseo",
+        gloss: "rosy",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Luteo",
+        gloss: "saffron-yellow",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Argenti",
+        gloss: "silver",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Auri",
+        gloss: "gold",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Ferru",
+        gloss: "rust",
         origin: Origin::Latin,
         category: Category::Colour,
     },
     Morpheme {
         text: "Hydro",
+        gloss: "water",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Pyro",
+        gloss: "fire",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Cryo",
+        gloss: "frost",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Geo",
+        gloss: "earth",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Aero",
+        gloss: "air",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Litho",
+        gloss: "stone",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Thermo",
+        gloss: "heat",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Photo",
+        gloss: "light",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Hygro",
+        gloss: "moist",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Xero",
+        gloss: "dry",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Halo",
+        gloss: "salt",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Psammo",
+        gloss: "sand",
         origin: Origin::Greek,
         category: Category::Environment,
     },
     Morpheme {
         text: "Aqu",
+        gloss: "water",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Mari",
+        gloss: "sea",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Monti",
+        gloss: "mountain",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Silvi",
+        gloss: "forest",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Glaci",
+        gloss: "ice",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Petri",
+        gloss: "rock",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Litori",
+        gloss: "shore",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Nivi",
+        gloss: "snow",
         origin: Origin::Latin,
         category: Category::Environment,
     },
     Morpheme {
         text: "Neo",
+        gloss: "new",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Paleo",
+        gloss: "ancient",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Archaeo",
+        gloss: "primeval",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Chrono",
+        gloss: "time",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Proto",
+        gloss: "first",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Eo",
+        gloss: "dawn",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Meso",
+        gloss: "middle",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Ceno",
+        gloss: "recent",
         origin: Origin::Greek,
         category: Category::Time,
     },
     Morpheme {
         text: "Novi",
+        gloss: "new",
         origin: Origin::Latin,
         category: Category::Time,
     },
     Morpheme {
         text: "Anti",
+        gloss: "before",
         origin: Origin::Latin,
         category: Category::Time,
     },
     Morpheme {
         text: "Primi",
+        gloss: "first",
         origin: Origin::Latin,
         category: Category::Time,
     },
     Morpheme {
         text: "Endo",
+        gloss: "within",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Ecto",
+        gloss: "outside",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Epi",
+        gloss: "upon",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Hypo",
+        gloss: "under",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Hyper",
+        gloss: "over",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Peri",
+        gloss: "around",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Para",
+        gloss: "beside",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Meta",
+        gloss: "beyond",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Ana",
+        gloss: "up",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Cata",
+        gloss: "down",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Amphi",
+        gloss: "on both sides",
         origin: Origin::Greek,
         category: Category::Position,
     },
     Morpheme {
         text: "Super",
+        gloss: "above",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Sub",
+        gloss: "below",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Trans",
+        gloss: "across",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Inter",
+        gloss: "between",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Infra",
+        gloss: "beneath",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Ultra",
+        gloss: "beyond",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Circum",
+        gloss: "around",
         origin: Origin::Latin,
         category: Category::Position,
     },
     Morpheme {
         text: "Mono",
+        gloss: "one",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Di",
+        gloss: "two",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Tri",
+        gloss: "three",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Tetra",
+        gloss: "four",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Penta",
+        gloss: "five",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Hexa",
+        gloss: "six",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Hepta",
+        gloss: "seven",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Octo",
+        gloss: "eight",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Ennea",
+        gloss: "nine",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Deca",
+        gloss: "ten",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Poly",
+        gloss: "many",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Oligo",
+        gloss: "few",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Diplo",
+        gloss: "double",
         origin: Origin::Greek,
         category: Category::Number,
     },
     Morpheme {
         text: "Uni",
+        gloss: "one",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Bi",
+        gloss: "two",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Quadri",
+        gloss: "four",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Multi",
+        gloss: "many",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Pluri",
+        gloss: "several",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Semi",
+        gloss: "half",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Pauci",
+        gloss: "few",
         origin: Origin::Latin,
         category: Category::Number,
     },
     Morpheme {
         text: "Morpho",
+        gloss: "form",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Platy",
+        gloss: "flat",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Strepto",
+        gloss: "twisted",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Cyclo",
+        gloss: "circle",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Spheno",
+        gloss: "wedge",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Sphaero",
+        gloss: "sphere",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Sclero",
+        gloss: "hard",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Trachy",
+        gloss: "rough",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Lopho",
+        gloss: "crest",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Ortho",
+        gloss: "straight",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Schizo",
+        gloss: "split",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Holo",
+        gloss: "whole",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Stereo",
+        gloss: "solid",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Stylo",
+        gloss: "pillar",
         origin: Origin::Greek,
         category: Category::Form,
     },
     Morpheme {
         text: "Plani",
+        gloss: "flat",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Curvi",
+        gloss: "curved",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Recti",
+        gloss: "straight",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Spiri",
+        gloss: "coil",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Globi",
+        gloss: "ball",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Squami",
+        gloss: "scale",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Stelli",
+        gloss: "star",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Rhombi",
+        gloss: "rhombus",
         origin: Origin::Latin,
         category: Category::Form,
     },
     Morpheme {
         text: "Crypto",
+        gloss: "hidden",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Pseudo",
+        gloss: "false",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Eu",
+        gloss: "true",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Hetero",
+        gloss: "different",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Homo",
+        gloss: "same",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Iso",
+        gloss: "equal",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Aniso",
+        gloss: "unequal",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Allo",
+        gloss: "other",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Auto",
+        gloss: "self",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Syn",
+        gloss: "together",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Apo",
+        gloss: "away from",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Gymno",
+        gloss: "naked",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Hapto",
+        gloss: "touch",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Acantho",
+        gloss: "thorn",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Actino",
+        gloss: "ray",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Tachy",
+        gloss: "swift",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Brady",
+        gloss: "slow",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Steno",
+        gloss: "narrow",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Eury",
+        gloss: "wide",
         origin: Origin::Greek,
         category: Category::Quality,
     },
     Morpheme {
         text: "Simpli",
+        gloss: "simple",
         origin: Origin::Latin,
         category: Category::Quality,
     },
     Morpheme {
         text: "Vari",
+        gloss: "varied",
         origin: Origin::Latin,
         category: Category::Quality,
     },
     Morpheme {
         text: "Vermi",
+        gloss: "worm",
         origin: Origin::Latin,
         category: Category::Quality,
     },
     Morpheme {
         text: "Serri",
+        gloss: "saw",
         origin: Origin::Latin,
         category: Category::Quality,
     },
     Morpheme {
         text: "Spini",
+        gloss: "spine",
         origin: Origin::Latin,
         category: Category::Quality,
     },
     Morpheme {
         text: "Totu",
+        gloss: "whole",
         origin: Origin::Latin,
         category: Category::Quality,
     },
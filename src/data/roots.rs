@@ -15,283 +15,1397 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-pub const ROOTS: &[&str] = &[
-    "cephal",
-    "crani",
-    "encephal",
-    "cerebr",
-    "neur",
-    "gangli",
-    "ocul",
-    "ophthalm",
-    "ot",
-    "audi",
-    "rhin",
-    "nas",
-    "osm",
-    "olfact",
-    "stom",
-    "gloss",
-    "lingu",
-    "dent",
-    "odont",
-    "maxill",
-    "mandibul",
-    "pharyn",
-    "laryng",
-    "esophag",
-    "gastr",
-    "enter",
-    "col",
-    "ile",
-    "duoden",
-    "jejun",
-    "rect",
-    "proc",
-    "hepat",
-    "cholecyst",
-    "pancreat",
-    "splanchn",
-    "viscer",
-    "pneum",
-    "pulmon",
-    "bronch",
-    "trache",
-    "pleur",
-    "laryng",
-    "cardi",
-    "cor",
-    "angi",
-    "vas",
-    "arteri",
-    "ven",
-    "phleb",
-    "hem",
-    "hemat",
-    "sangui",
-    "thromb",
-    "embol",
-    "nephr",
-    "ren",
-    "ureter",
-    "urethr",
-    "cyst",
-    "vesic",
-    "orch",
-    "test",
-    "ovar",
-    "uter",
-    "hyster",
-    "metr",
-    "gonad",
-    "sper",
-    "spor",
-    "gon",
-    "game",
-    "oste",
-    "arthr",
-    "chondr",
-    "cost",
-    "spondyl",
-    "vertebr",
-    "stern",
-    "pelv",
-    "femor",
-    "tibi",
-    "fibul",
-    "carp",
-    "tars",
-    "phalang",
-    "clav",
-    "scapul",
-    "my",
-    "muscul",
-    "sarc",
-    "ton",
-    "derm",
-    "dermat",
-    "cutane",
-    "pil",
-    "trich",
-    "onych",
-    "ungui",
-    "seb",
-    "sudor",
-    "hidr",
-    "thyroid",
-    "adren",
-    "hypophys",
-    "pituit",
-    "thym",
-    "pineal",
-    "pancre",
-    "insul",
-    "dactyl",
-    "digit",
-    "cheir",
-    "manu",
-    "brachi",
-    "pod",
-    "ped",
-    "pteryx",
-    "pter",
-    "pinn",
-    "cervic",
-    "coll",
-    "rachi",
-    "nuch",
-    "cyt",
-    "cellul",
-    "blast",
-    "cyst",
-    "thec",
-    "som",
-    "hist",
-    "epithel",
-    "stroma",
-    "fibr",
-    "tela",
-    "membran",
-    "tegument",
-    "capsul",
-    "fascicul",
-    "strat",
-    "lamina",
-    "trab",
-    "lymph",
-    "ser",
-    "plasma",
-    "chyl",
-    "humor",
-    "muc",
-    "pus",
-    "optic",
-    "acous",
-    "gust",
-    "palat",
-    "tacti",
-    "sens",
-    "troph",
-    "metabol",
-    "nutri",
-    "aliment",
-    "phag",
-    "kines",
-    "mot",
-    "pher",
-    "duc",
-    "grad",
-    "ambul",
-    "vol",
-    "nat",
-    "curs",
-    "sali",
-    "rept",
-    "secre",
-    "crine",
-    "excre",
-    "gen",
-    "genesis",
-    "gon",
-    "blast",
-    "plasia",
-    "trophy",
-    "auxe",
-    "lys",
-    "clas",
-    "phag",
-    "necr",
-    "thanat",
-    "morph",
-    "eid",
-    "schem",
-    "typos",
-    "taxis",
-    "trope",
-    "kinesis",
-    "phasis",
-    "phoria",
-    "ptosis",
-    "spasm",
-    "ton",
-    "clasis",
-    "phyll",
-    "foli",
-    "rhiz",
-    "radic",
-    "caul",
-    "cauli",
-    "rami",
-    "flor",
-    "anth",
-    "pet",
-    "sep",
-    "carp",
-    "fruct",
-    "semen",
-    "grani",
-    "spic",
-    "bacca",
-    "myceli",
-    "hypha",
-    "spor",
-    "ascus",
-    "basid",
-    "thec",
-    "pil",
-    "stip",
-    "lamell",
-    "trama",
-    "hymeni",
-    "saur",
-    "pter",
-    "pter",
-    "branch",
-    "naut",
-    "chela",
-    "antenna",
-    "rostr",
-    "prob",
-    "seta",
-    "squam",
-    "pinnul",
-    "chrom",
-    "lith",
-    "crystal",
-    "vitr",
-    "calci",
-    "ferri",
-    "argent",
-    "aur",
-    "plumb",
-    "cupr",
-    "hydr",
-    "oxy",
-    "carbo",
-    "azot",
-    "phosph",
-    "sulf",
-    "chlor",
-    "levi",
-    "glabr",
-    "hirsut",
-    "vill",
-    "toment",
-    "seric",
-    "scabr",
-    "asper",
-    "papill",
-    "later",
-    "medi",
-    "centr",
-    "apic",
-    "bas",
-    "termin",
-    "margin",
-    "dors",
-    "ventr",
-    "proxim",
-    "dist",
-    "anter",
-    "poster",
+use super::morphemes::{Origin, Root};
+
+pub const ROOTS: &[Root] = &[
+    Root {
+        text: "cephal",
+        gloss: "head",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "crani",
+        gloss: "skull",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "encephal",
+        gloss: "brain",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cerebr",
+        gloss: "brain",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "neur",
+        gloss: "nerve",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gangli",
+        gloss: "nerve knot",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ocul",
+        gloss: "eye",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ophthalm",
+        gloss: "eye",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ot",
+        gloss: "ear",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "audi",
+        gloss: "hearing",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rhin",
+        gloss: "nose",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nas",
+        gloss: "nose",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "osm",
+        gloss: "smell",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "olfact",
+        gloss: "smell",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "stom",
+        gloss: "mouth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gloss",
+        gloss: "tongue",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lingu",
+        gloss: "tongue",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dent",
+        gloss: "tooth",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "odont",
+        gloss: "tooth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "maxill",
+        gloss: "jaw",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mandibul",
+        gloss: "jaw",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pharyn",
+        gloss: "throat",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "laryng",
+        gloss: "larynx",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "esophag",
+        gloss: "gullet",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gastr",
+        gloss: "stomach",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "enter",
+        gloss: "gut",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "col",
+        gloss: "colon",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ile",
+        gloss: "ileum",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "duoden",
+        gloss: "duodenum",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "jejun",
+        gloss: "jejunum",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rect",
+        gloss: "rectum",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "proc",
+        gloss: "anus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hepat",
+        gloss: "liver",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cholecyst",
+        gloss: "gall bladder",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pancreat",
+        gloss: "pancreas",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "splanchn",
+        gloss: "innards",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "viscer",
+        gloss: "innards",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pneum",
+        gloss: "lung",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pulmon",
+        gloss: "lung",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bronch",
+        gloss: "windpipe",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "trache",
+        gloss: "windpipe",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pleur",
+        gloss: "side",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "laryng",
+        gloss: "larynx",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cardi",
+        gloss: "heart",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cor",
+        gloss: "heart",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "angi",
+        gloss: "vessel",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vas",
+        gloss: "vessel",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "arteri",
+        gloss: "artery",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ven",
+        gloss: "vein",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phleb",
+        gloss: "vein",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hem",
+        gloss: "blood",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hemat",
+        gloss: "blood",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sangui",
+        gloss: "blood",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "thromb",
+        gloss: "clot",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "embol",
+        gloss: "plug",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nephr",
+        gloss: "kidney",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ren",
+        gloss: "kidney",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ureter",
+        gloss: "ureter",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "urethr",
+        gloss: "urethra",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cyst",
+        gloss: "bladder",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vesic",
+        gloss: "bladder",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "orch",
+        gloss: "testicle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "test",
+        gloss: "testicle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ovar",
+        gloss: "ovary",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "uter",
+        gloss: "womb",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hyster",
+        gloss: "womb",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "metr",
+        gloss: "womb",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gonad",
+        gloss: "gonad",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sper",
+        gloss: "seed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "spor",
+        gloss: "spore",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gon",
+        gloss: "seed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "game",
+        gloss: "marriage",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "oste",
+        gloss: "bone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "arthr",
+        gloss: "joint",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chondr",
+        gloss: "cartilage",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cost",
+        gloss: "rib",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spondyl",
+        gloss: "vertebra",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vertebr",
+        gloss: "vertebra",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "stern",
+        gloss: "breastbone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pelv",
+        gloss: "basin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "femor",
+        gloss: "thigh",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tibi",
+        gloss: "shin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fibul",
+        gloss: "clasp",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "carp",
+        gloss: "wrist; fruit",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tars",
+        gloss: "ankle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phalang",
+        gloss: "finger bone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "clav",
+        gloss: "key",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scapul",
+        gloss: "shoulder blade",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "my",
+        gloss: "muscle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "muscul",
+        gloss: "muscle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sarc",
+        gloss: "flesh",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ton",
+        gloss: "tension",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "derm",
+        gloss: "skin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "dermat",
+        gloss: "skin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cutane",
+        gloss: "skin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pil",
+        gloss: "hair; cap",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "trich",
+        gloss: "hair",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "onych",
+        gloss: "nail",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ungui",
+        gloss: "nail",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "seb",
+        gloss: "tallow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sudor",
+        gloss: "sweat",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hidr",
+        gloss: "sweat",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thyroid",
+        gloss: "shield-shaped",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "adren",
+        gloss: "near the kidneys",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hypophys",
+        gloss: "undergrowth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pituit",
+        gloss: "phlegm",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "thym",
+        gloss: "thymus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pineal",
+        gloss: "pine cone",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pancre",
+        gloss: "pancreas",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "insul",
+        gloss: "island",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dactyl",
+        gloss: "finger",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "digit",
+        gloss: "finger",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cheir",
+        gloss: "hand",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "manu",
+        gloss: "hand",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "brachi",
+        gloss: "arm",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pod",
+        gloss: "foot",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ped",
+        gloss: "foot",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pteryx",
+        gloss: "wing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pter",
+        gloss: "wing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pinn",
+        gloss: "feather",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cervic",
+        gloss: "neck",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "coll",
+        gloss: "neck",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rachi",
+        gloss: "spine",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nuch",
+        gloss: "nape",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cyt",
+        gloss: "cell",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cellul",
+        gloss: "small room",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "blast",
+        gloss: "bud",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cyst",
+        gloss: "bladder",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thec",
+        gloss: "case",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "som",
+        gloss: "body",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hist",
+        gloss: "tissue",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "epithel",
+        gloss: "lining",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stroma",
+        gloss: "bedding",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fibr",
+        gloss: "fibre",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tela",
+        gloss: "web",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "membran",
+        gloss: "membrane",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tegument",
+        gloss: "covering",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "capsul",
+        gloss: "little box",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fascicul",
+        gloss: "little bundle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "strat",
+        gloss: "layer",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lamina",
+        gloss: "plate",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "trab",
+        gloss: "beam",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lymph",
+        gloss: "clear water",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ser",
+        gloss: "whey",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "plasma",
+        gloss: "something moulded",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chyl",
+        gloss: "juice",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "humor",
+        gloss: "fluid",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "muc",
+        gloss: "mucus",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pus",
+        gloss: "pus",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "optic",
+        gloss: "sight",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "acous",
+        gloss: "hearing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gust",
+        gloss: "taste",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "palat",
+        gloss: "palate",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tacti",
+        gloss: "touch",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sens",
+        gloss: "sense",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "troph",
+        gloss: "nourishment",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "metabol",
+        gloss: "change",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nutri",
+        gloss: "nourishment",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aliment",
+        gloss: "food",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phag",
+        gloss: "eating",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "kines",
+        gloss: "movement",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "mot",
+        gloss: "motion",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pher",
+        gloss: "carrying",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "duc",
+        gloss: "leading",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "grad",
+        gloss: "stepping",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ambul",
+        gloss: "walking",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vol",
+        gloss: "flying",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nat",
+        gloss: "swimming",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "curs",
+        gloss: "running",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sali",
+        gloss: "leaping",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rept",
+        gloss: "creeping",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "secre",
+        gloss: "secretion",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "crine",
+        gloss: "secreting",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "excre",
+        gloss: "excretion",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gen",
+        gloss: "birth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "genesis",
+        gloss: "origin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gon",
+        gloss: "seed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "blast",
+        gloss: "bud",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "plasia",
+        gloss: "moulding",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "trophy",
+        gloss: "nourishment",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "auxe",
+        gloss: "growth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lys",
+        gloss: "loosening",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "clas",
+        gloss: "breaking",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phag",
+        gloss: "eating",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "necr",
+        gloss: "corpse",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thanat",
+        gloss: "death",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "morph",
+        gloss: "form",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "eid",
+        gloss: "likeness",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "schem",
+        gloss: "shape",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "typos",
+        gloss: "imprint",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "taxis",
+        gloss: "arrangement",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "trope",
+        gloss: "turning",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "kinesis",
+        gloss: "movement",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phasis",
+        gloss: "utterance",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phoria",
+        gloss: "bearing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ptosis",
+        gloss: "falling",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "spasm",
+        gloss: "cramp",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ton",
+        gloss: "tension",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "clasis",
+        gloss: "breaking",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phyll",
+        gloss: "leaf",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "foli",
+        gloss: "leaf",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rhiz",
+        gloss: "root",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "radic",
+        gloss: "root",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "caul",
+        gloss: "stem",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cauli",
+        gloss: "stem",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rami",
+        gloss: "branch",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "flor",
+        gloss: "flower",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "anth",
+        gloss: "flower",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pet",
+        gloss: "petal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sep",
+        gloss: "sepal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "carp",
+        gloss: "wrist; fruit",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fruct",
+        gloss: "fruit",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "semen",
+        gloss: "seed",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "grani",
+        gloss: "grain",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spic",
+        gloss: "spike",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bacca",
+        gloss: "berry",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "myceli",
+        gloss: "mycelium",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hypha",
+        gloss: "web",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "spor",
+        gloss: "spore",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ascus",
+        gloss: "sac",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "basid",
+        gloss: "little base",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thec",
+        gloss: "case",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pil",
+        gloss: "hair; cap",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "stip",
+        gloss: "stalk",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lamell",
+        gloss: "thin plate",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "trama",
+        gloss: "weft",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hymeni",
+        gloss: "membrane",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "saur",
+        gloss: "lizard",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pter",
+        gloss: "wing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pter",
+        gloss: "wing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "branch",
+        gloss: "gill",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "naut",
+        gloss: "sailor",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chela",
+        gloss: "claw",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "antenna",
+        gloss: "sail yard",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rostr",
+        gloss: "beak",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "prob",
+        gloss: "trunk",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "seta",
+        gloss: "bristle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "squam",
+        gloss: "scale",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pinnul",
+        gloss: "little feather",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chrom",
+        gloss: "colour",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lith",
+        gloss: "stone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "crystal",
+        gloss: "ice",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vitr",
+        gloss: "glass",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "calci",
+        gloss: "lime",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ferri",
+        gloss: "iron",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "argent",
+        gloss: "silver",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aur",
+        gloss: "gold",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "plumb",
+        gloss: "lead",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cupr",
+        gloss: "copper",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hydr",
+        gloss: "water",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "oxy",
+        gloss: "sharp",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "carbo",
+        gloss: "charcoal",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "azot",
+        gloss: "lifeless",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phosph",
+        gloss: "light-bearing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sulf",
+        gloss: "sulphur",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chlor",
+        gloss: "green",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "levi",
+        gloss: "smooth",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "glabr",
+        gloss: "hairless",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hirsut",
+        gloss: "shaggy",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vill",
+        gloss: "tuft of hair",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "toment",
+        gloss: "down",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "seric",
+        gloss: "silk",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scabr",
+        gloss: "rough",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "asper",
+        gloss: "rough",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "papill",
+        gloss: "nipple",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "later",
+        gloss: "side",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "medi",
+        gloss: "middle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "centr",
+        gloss: "centre",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "apic",
+        gloss: "tip",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bas",
+        gloss: "base",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "termin",
+        gloss: "end",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "margin",
+        gloss: "edge",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dors",
+        gloss: "back",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ventr",
+        gloss: "belly",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "proxim",
+        gloss: "nearest",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dist",
+        gloss: "distant",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "anter",
+        gloss: "front",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "poster",
+        gloss: "rear",
+        origin: Some(Origin::Latin),
+    },
 ];
@@ -15,575 +15,1543 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-pub const GENUS_SUFFIXES: &[&str] = &[
-    "us", "os", "es", "is", "a", "e", "as", "um", "on", "ma", "er", "or", "en", "yx", "ix", "ax",
+use super::morphemes::{Origin, Suffix};
+
+pub const GENUS_SUFFIXES: &[Suffix] = &[
+    Suffix {
+        text: "us",
+        gloss: "one",
+        origin: None,
+    },
+    Suffix {
+        text: "os",
+        gloss: "one",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "es",
+        gloss: "one",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "is",
+        gloss: "one",
+        origin: None,
+    },
+    Suffix {
+        text: "a",
+        gloss: "one",
+        origin: None,
+    },
+    Suffix {
+        text: "e",
+        gloss: "one",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "as",
+        gloss: "one",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "um",
+        gloss: "thing",
+        origin: None,
+    },
+    Suffix {
+        text: "on",
+        gloss: "thing",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "ma",
+        gloss: "result",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "er",
+        gloss: "one",
+        origin: Some(Origin::Latin),
+    },
+    Suffix {
+        text: "or",
+        gloss: "doer",
+        origin: Some(Origin::Latin),
+    },
+    Suffix {
+        text: "en",
+        gloss: "means",
+        origin: Some(Origin::Latin),
+    },
+    Suffix {
+        text: "yx",
+        gloss: "thing",
+        origin: Some(Origin::Greek),
+    },
+    Suffix {
+        text: "ix",
+        gloss: "doer",
+        origin: Some(Origin::Latin),
+    },
+    Suffix {
+        text: "ax",
+        gloss: "thing",
+        origin: Some(Origin::Greek),
+    },
 ];
 
-use super::morphemes::{Category, SpeciesDescriptor};
+use super::morphemes::{Category, Declension, SpeciesDescriptor};
 
 pub const SPECIES_DESCRIPTORS: &[SpeciesDescriptor] = &[
     SpeciesDescriptor {
         text: "robustus",
+        gloss: "sturdy",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "validus",
+        gloss: "strong",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "gracilis",
+        gloss: "slender",
         category: Some(Category::Size),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "tenuis",
+        gloss: "thin",
         category: Some(Category::Size),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "crassus",
+        gloss: "thick",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "densus",
+        gloss: "dense",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "solidus",
+        gloss: "solid",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "pinguis",
+        gloss: "fat",
         category: Some(Category::Size),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "obesus",
+        gloss: "stout",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "macilentus",
+        gloss: "lean",
         category: Some(Category::Size),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "pallidus",
+        gloss: "pale",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "obscurus",
+        gloss: "dark",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "lucidus",
+        gloss: "bright",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "nitidus",
+        gloss: "shining",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "opacus",
+        gloss: "shaded",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "maculatus",
+        gloss: "spotted",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "striatus",
+        gloss: "striped",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "variegatus",
+        gloss: "variegated",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "pictus",
+        gloss: "painted",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "tinctus",
+        gloss: "tinged",
         category: Some(Category::Colour),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "humidus",
+        gloss: "moist",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "siccus",
+        gloss: "dry",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "frigidus",
+        gloss: "cold",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "calidus",
+        gloss: "warm",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "umbratus",
+        gloss: "shaded",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "apricus",
+        gloss: "sunny",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "ventosus",
+        gloss: "windy",
         category: Some(Category::Environment),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "pluvialis",
+        gloss: "of rain",
         category: Some(Category::Environment),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "nivalis",
+        gloss: "of snow",
         category: Some(Category::Environment),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "rupicola",
+        gloss: "rock-dweller",
         category: Some(Category::Environment),
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "temporalis",
+        gloss: "seasonal",
         category: Some(Category::Time),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "aeternus",
+        gloss: "everlasting",
         category: Some(Category::Time),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "diurnus",
+        gloss: "of the day",
         category: Some(Category::Time),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "nocturnus",
+        gloss: "of the night",
         category: Some(Category::Time),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "matutinus",
+        gloss: "of the morning",
         category: Some(Category::Time),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "vespertinus",
+        gloss: "of the evening",
         category: Some(Category::Time),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "vernalis",
+        gloss: "of spring",
         category: Some(Category::Time),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "aestivus",
+        gloss: "of summer",
         category: Some(Category::Time),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "autumnalis",
+        gloss: "of autumn",
         category: Some(Category::Time),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "hiemalis",
+        gloss: "of winter",
         category: Some(Category::Time),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "medianus",
+        gloss: "in the middle",
         category: Some(Category::Position),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "lateralis",
+        gloss: "of the side",
         category: Some(Category::Position),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "centralis",
+        gloss: "central",
         category: Some(Category::Position),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "periphericus",
+        gloss: "of the edge",
         category: Some(Category::Position),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "extremus",
+        gloss: "outermost",
         category: Some(Category::Position),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "medius",
+        gloss: "middle",
         category: Some(Category::Position),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "imus",
+        gloss: "lowest",
         category: Some(Category::Position),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "summus",
+        gloss: "highest",
         category: Some(Category::Position),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "aggregatus",
+        gloss: "clustered",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "dispersus",
+        gloss: "scattered",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "confertus",
+        gloss: "crowded",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "sparsus",
+        gloss: "sparse",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "copiousus",
+        gloss: "plentiful",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "solitarius",
+        gloss: "solitary",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "gregarius",
+        gloss: "living in flocks",
         category: Some(Category::Number),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "colonialis",
+        gloss: "living in colonies",
         category: Some(Category::Number),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "regularis",
+        gloss: "regular",
         category: Some(Category::Form),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "irregularis",
+        gloss: "irregular",
         category: Some(Category::Form),
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "symmetricus",
+        gloss: "symmetrical",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "asymmetricus",
+        gloss: "asymmetrical",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "compressus",
+        gloss: "pressed together",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "depressus",
+        gloss: "flattened",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "inflatus",
+        gloss: "swollen",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "contortus",
+        gloss: "twisted",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "flexuosus",
+        gloss: "winding",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "undulatus",
+        gloss: "wavy",
         category: Some(Category::Form),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "perfectus",
+        gloss: "perfect",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "imperfectus",
+        gloss: "imperfect",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "completus",
+        gloss: "complete",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "incompletus",
+        gloss: "incomplete",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "verus",
+        gloss: "true",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "falsus",
+        gloss: "false",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "spurius",
+        gloss: "spurious",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "hybridus",
+        gloss: "hybrid",
         category: Some(Category::Quality),
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "alpinus",
+        gloss: "alpine",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "maritimus",
+        gloss: "of the sea",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "montanus",
+        gloss: "of mountains",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "campestris",
+        gloss: "of fields",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "sylvaticus",
+        gloss: "of woods",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "urbanus",
+        gloss: "of the city",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "borealis",
+        gloss: "northern",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "australis",
+        gloss: "southern",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "orientalis",
+        gloss: "eastern",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "occidentalis",
+        gloss: "western",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "insularis",
+        gloss: "of islands",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "rupestris",
+        gloss: "of rocks",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "pratensis",
+        gloss: "of meadows",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "paludosus",
+        gloss: "marshy",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "lacustris",
+        gloss: "of lakes",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "fluvialis",
+        gloss: "of rivers",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "riparius",
+        gloss: "of riverbanks",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "terrestris",
+        gloss: "of the land",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "arenarius",
+        gloss: "of sand",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "saxatilis",
+        gloss: "among rocks",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "elegans",
+        gloss: "elegant",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "formosus",
+        gloss: "beautiful",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "pulcher",
+        gloss: "beautiful",
         category: None,
+        declension: Declension::ErRaRum,
     },
     SpeciesDescriptor {
         text: "ornatus",
+        gloss: "adorned",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "decorus",
+        gloss: "comely",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "venustus",
+        gloss: "charming",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "spectabilis",
+        gloss: "remarkable",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "insignis",
+        gloss: "distinguished",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "eximius",
+        gloss: "exceptional",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "admirabilis",
+        gloss: "admirable",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "mirabilis",
+        gloss: "wonderful",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "horridus",
+        gloss: "bristling",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "deformis",
+        gloss: "misshapen",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "monstrosus",
+        gloss: "monstrous",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "velox",
+        gloss: "swift",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "agilis",
+        gloss: "nimble",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "tardus",
+        gloss: "slow",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "quietus",
+        gloss: "calm",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "errans",
+        gloss: "wandering",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "vagans",
+        gloss: "roaming",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "migrans",
+        gloss: "migrating",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "sedentarius",
+        gloss: "sedentary",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "pugnax",
+        gloss: "combative",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "timidus",
+        gloss: "timid",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "audax",
+        gloss: "bold",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "ferox",
+        gloss: "fierce",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "vulgaris",
+        gloss: "common",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "communis",
+        gloss: "common",
         category: None,
+        declension: Declension::IsIsE,
     },
     SpeciesDescriptor {
         text: "rarus",
+        gloss: "rare",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "frequens",
+        gloss: "frequent",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "abundans",
+        gloss: "abundant",
         category: None,
+        declension: Declension::Invariant,
     },
     SpeciesDescriptor {
         text: "parasiticus",
+        gloss: "parasitic",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "symbioticus",
+        gloss: "living together",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "saprophyticus",
+        gloss: "feeding on decay",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "epiphyticus",
+        gloss: "growing on plants",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "domesticus",
+        gloss: "domestic",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "ferus",
+        gloss: "wild",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "cultivatus",
+        gloss: "cultivated",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "major",
+        gloss: "greater",
         category: None,
+        declension: Declension::OrOrUs,
     },
     SpeciesDescriptor {
         text: "minor",
+        gloss: "lesser",
         category: None,
+        declension: Declension::OrOrUs,
     },
     SpeciesDescriptor {
         text: "medius",
+        gloss: "middle",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "paradoxus",
+        gloss: "unexpected",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "insolitus",
+        gloss: "unusual",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "curiosus",
+        gloss: "curious",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "dubius",
+        gloss: "doubtful",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "ambiguus",
+        gloss: "ambiguous",
         category: None,
+        declension: Declension::UsAUm,
     },
     SpeciesDescriptor {
         text: "incertus",
+        gloss: "uncertain",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+];
+
+/// Third epithets for trinomial names. Mostly geographic, as subspecies
+/// names tend to be.
+pub const SUBSPECIES_DESCRIPTORS: &[SpeciesDescriptor] = &[
+    SpeciesDescriptor {
+        text: "borealis",
+        gloss: "northern",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "australis",
+        gloss: "southern",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "orientalis",
+        gloss: "eastern",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "occidentalis",
+        gloss: "western",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "septentrionalis",
+        gloss: "northern",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "meridionalis",
+        gloss: "southern",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "insularis",
+        gloss: "of islands",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "continentalis",
+        gloss: "of the mainland",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "montanus",
+        gloss: "of mountains",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "collinus",
+        gloss: "of hills",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "campestris",
+        gloss: "of fields",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "littoralis",
+        gloss: "of the shore",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "maritimus",
+        gloss: "of the sea",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "fluviatilis",
+        gloss: "of rivers",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "lacustris",
+        gloss: "of lakes",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "palustris",
+        gloss: "of marshes",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "silvestris",
+        gloss: "of woods",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "nemoralis",
+        gloss: "of groves",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "arcticus",
+        gloss: "arctic",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "tropicus",
+        gloss: "tropical",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "polaris",
+        gloss: "polar",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "aequatorialis",
+        gloss: "equatorial",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "atlanticus",
+        gloss: "Atlantic",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "pacificus",
+        gloss: "Pacific",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "indicus",
+        gloss: "Indian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "mediterraneus",
+        gloss: "Mediterranean",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "balticus",
+        gloss: "Baltic",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "caspius",
+        gloss: "Caspian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "arabicus",
+        gloss: "Arabian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "africanus",
+        gloss: "African",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "americanus",
+        gloss: "American",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "asiaticus",
+        gloss: "Asian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "europaeus",
+        gloss: "European",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "sinensis",
+        gloss: "Chinese",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "japonicus",
+        gloss: "Japanese",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "coreanus",
+        gloss: "Korean",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "siculus",
+        gloss: "Sicilian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "creticus",
+        gloss: "Cretan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "cyprius",
+        gloss: "Cypriot",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "hispanicus",
+        gloss: "Spanish",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "gallicus",
+        gloss: "Gaulish",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "italicus",
+        gloss: "Italian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "germanicus",
+        gloss: "German",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "britannicus",
+        gloss: "British",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "hibernicus",
+        gloss: "Irish",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "scoticus",
+        gloss: "Scottish",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "islandicus",
+        gloss: "Icelandic",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "norvegicus",
+        gloss: "Norwegian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "suecicus",
+        gloss: "Swedish",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "fennicus",
+        gloss: "Finnish",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "rossicus",
+        gloss: "Russian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "sibiricus",
+        gloss: "Siberian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "mongolicus",
+        gloss: "Mongolian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "tibetanus",
+        gloss: "Tibetan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "persicus",
+        gloss: "Persian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "anatolicus",
+        gloss: "Anatolian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "graecus",
+        gloss: "Greek",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "balcanicus",
+        gloss: "Balkan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "carpaticus",
+        gloss: "Carpathian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "pyrenaicus",
+        gloss: "Pyrenean",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "caucasicus",
+        gloss: "Caucasian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "andinus",
+        gloss: "Andean",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "amazonicus",
+        gloss: "Amazonian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "patagonicus",
+        gloss: "Patagonian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "caribaeus",
+        gloss: "Caribbean",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "mexicanus",
+        gloss: "Mexican",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "canadensis",
+        gloss: "Canadian",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "californicus",
+        gloss: "Californian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "floridanus",
+        gloss: "Floridian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "texanus",
+        gloss: "Texan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "capensis",
+        gloss: "of the Cape",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "niloticus",
+        gloss: "of the Nile",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "saharicus",
+        gloss: "Saharan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "aethiopicus",
+        gloss: "Ethiopian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "malayanus",
+        gloss: "Malayan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "javanicus",
+        gloss: "Javanese",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "sumatranus",
+        gloss: "Sumatran",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "borneensis",
+        gloss: "of Borneo",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "papuanus",
+        gloss: "Papuan",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "tasmanicus",
+        gloss: "Tasmanian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "zelandicus",
+        gloss: "of New Zealand",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "alaskensis",
+        gloss: "of Alaska",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "groenlandicus",
+        gloss: "of Greenland",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "lusitanicus",
+        gloss: "Portuguese",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "sardus",
+        gloss: "Sardinian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "corsicus",
+        gloss: "Corsican",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "helveticus",
+        gloss: "Swiss",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "danubialis",
+        gloss: "of the Danube",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "rhenanus",
+        gloss: "of the Rhine",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "uralensis",
+        gloss: "of the Urals",
+        category: None,
+        declension: Declension::IsIsE,
+    },
+    SpeciesDescriptor {
+        text: "altaicus",
+        gloss: "of the Altai",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "ponticus",
+        gloss: "of the Black Sea",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "illyricus",
+        gloss: "Illyrian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "dalmaticus",
+        gloss: "Dalmatian",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "typicus",
+        gloss: "typical",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "intermedius",
+        gloss: "intermediate",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "transiens",
+        gloss: "passing over",
+        category: None,
+        declension: Declension::Invariant,
+    },
+    SpeciesDescriptor {
+        text: "vicarius",
+        gloss: "substitute",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "occultus",
+        gloss: "hidden",
+        category: None,
+        declension: Declension::UsAUm,
+    },
+    SpeciesDescriptor {
+        text: "remotus",
+        gloss: "remote",
         category: None,
+        declension: Declension::UsAUm,
     },
 ];
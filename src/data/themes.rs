@@ -0,0 +1,3171 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::morphemes::{Origin, Root};
+
+/// Roots on a theme, used in place of the anatomical [`super::ROOTS`].
+/// Names depend on the order of the roots, so a theme is frozen once
+/// released: its fingerprint covers the list, and changes go into a new
+/// theme.
+#[derive(Debug, PartialEq)]
+pub struct Theme {
+    pub id: &'static str,
+    pub roots: &'static [Root<'static>],
+    pub fingerprint: u64,
+}
+
+pub const THEMES: &[Theme] = &[
+    Theme {
+        id: "marine",
+        roots: MARINE_ROOTS,
+        fingerprint: 0xa2d4435e2758601c,
+    },
+    Theme {
+        id: "botanical",
+        roots: BOTANICAL_ROOTS,
+        fingerprint: 0x68bd5b4c6385542b,
+    },
+    Theme {
+        id: "mineral",
+        roots: MINERAL_ROOTS,
+        fingerprint: 0x7182d13266cc4df9,
+    },
+    Theme {
+        id: "celestial",
+        roots: CELESTIAL_ROOTS,
+        fingerprint: 0xc20717cf0349089f,
+    },
+    Theme {
+        id: "acoustic",
+        roots: ACOUSTIC_ROOTS,
+        fingerprint: 0x174a61e590ca0b4a,
+    },
+];
+
+pub fn find_theme(id: &str) -> Option<&'static Theme> {
+    THEMES.iter().find(|theme| theme.id == id)
+}
+
+const MARINE_ROOTS: &[Root] = &[
+    Root {
+        text: "thalass",
+        gloss: "sea",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pelag",
+        gloss: "open sea",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "bath",
+        gloss: "depth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ocean",
+        gloss: "ocean",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "mar",
+        gloss: "sea",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aqu",
+        gloss: "water",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cym",
+        gloss: "wave",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fluct",
+        gloss: "wave",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "und",
+        gloss: "wave",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spum",
+        gloss: "foam",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sal",
+        gloss: "salt",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hal",
+        gloss: "salt",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ichthy",
+        gloss: "fish",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pisc",
+        gloss: "fish",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "selach",
+        gloss: "shark",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "carchar",
+        gloss: "shark",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "squal",
+        gloss: "shark",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "raj",
+        gloss: "ray",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "delphin",
+        gloss: "dolphin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phoc",
+        gloss: "seal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cet",
+        gloss: "whale",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "balaen",
+        gloss: "whale",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "teuth",
+        gloss: "squid",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sepi",
+        gloss: "cuttlefish",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "octop",
+        gloss: "octopus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "polyp",
+        gloss: "many-footed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "medus",
+        gloss: "jellyfish",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "actin",
+        gloss: "ray",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "corall",
+        gloss: "coral",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "spong",
+        gloss: "sponge",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "conch",
+        gloss: "shell",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ostre",
+        gloss: "oyster",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mytil",
+        gloss: "mussel",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pectin",
+        gloss: "comb",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "carcin",
+        gloss: "crab",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "astac",
+        gloss: "crayfish",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "homar",
+        gloss: "lobster",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "carid",
+        gloss: "shrimp",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gammar",
+        gloss: "lobster",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "plankt",
+        gloss: "drifting",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nect",
+        gloss: "swimming",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "alg",
+        gloss: "seaweed",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phyc",
+        gloss: "seaweed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fuc",
+        gloss: "rockweed",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "laminar",
+        gloss: "kelp",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "zoster",
+        gloss: "girdle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "litor",
+        gloss: "shore",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aestu",
+        gloss: "tide",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lagun",
+        gloss: "lagoon",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sinu",
+        gloss: "bay",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "portu",
+        gloss: "harbour",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nav",
+        gloss: "ship",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ancor",
+        gloss: "anchor",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vel",
+        gloss: "sail",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nere",
+        gloss: "sea nymph",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "triton",
+        gloss: "Triton",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "siren",
+        gloss: "siren",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thetid",
+        gloss: "Thetis",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "glauc",
+        gloss: "sea-green",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "margarit",
+        gloss: "pearl",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nacr",
+        gloss: "mother-of-pearl",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ostrac",
+        gloss: "shell",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "strombid",
+        gloss: "conch",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "muric",
+        gloss: "murex",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "buccin",
+        gloss: "trumpet",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nass",
+        gloss: "wicker basket",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "patell",
+        gloss: "small dish",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "haliot",
+        gloss: "sea ear",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chit",
+        gloss: "tunic",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "echin",
+        gloss: "sea urchin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ophiur",
+        gloss: "snake tail",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "holothur",
+        gloss: "sea cucumber",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "crinoid",
+        gloss: "lily-like",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "salp",
+        gloss: "salp",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tunic",
+        gloss: "tunic",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ascidi",
+        gloss: "little wineskin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "bryoz",
+        gloss: "moss animal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "abyss",
+        gloss: "bottomless",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "scopul",
+        gloss: "reef",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "arenicol",
+        gloss: "sand dweller",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "limn",
+        gloss: "lake",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thalamit",
+        gloss: "lowest rower",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pagur",
+        gloss: "hermit crab",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "majid",
+        gloss: "spider crab",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "portun",
+        gloss: "swimming crab",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cyprae",
+        gloss: "cowrie",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "argonaut",
+        gloss: "argonaut",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nautil",
+        gloss: "sailor",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "loligin",
+        gloss: "squid",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "architeuth",
+        gloss: "chief squid",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "mol",
+        gloss: "millstone",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rhod",
+        gloss: "rose",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "syngnath",
+        gloss: "joined jaws",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hippocamp",
+        gloss: "seahorse",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "exocoet",
+        gloss: "flying fish",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thunn",
+        gloss: "tuna",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scomb",
+        gloss: "mackerel",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gad",
+        gloss: "cod",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pleuronect",
+        gloss: "side-swimmer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "mur",
+        gloss: "moray",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "angull",
+        gloss: "eel",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spongi",
+        gloss: "sponge",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "calcisp",
+        gloss: "chalk sponge",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "siphon",
+        gloss: "tube",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "velell",
+        gloss: "little sail",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "physal",
+        gloss: "bladder",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ctenophor",
+        gloss: "comb-bearer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "bero",
+        gloss: "comb jelly",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "diatom",
+        gloss: "cut in two",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "coccolith",
+        gloss: "berry stone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "foramin",
+        gloss: "opening",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "radiolar",
+        gloss: "little ray",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "globiger",
+        gloss: "globe-bearing",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "noctiluc",
+        gloss: "night light",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cymotho",
+        gloss: "wave-swift",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "isopod",
+        gloss: "equal-footed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "copepod",
+        gloss: "oar-footed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "balan",
+        gloss: "acorn",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lepad",
+        gloss: "limpet",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cirr",
+        gloss: "curl",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "oper",
+        gloss: "lid",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "remor",
+        gloss: "delay",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "echeneid",
+        gloss: "ship-holder",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thetys",
+        gloss: "Tethys",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "dorid",
+        gloss: "sea slug",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aplysi",
+        gloss: "sea hare",
+        origin: Some(Origin::Greek),
+    },
+];
+
+const BOTANICAL_ROOTS: &[Root] = &[
+    Root {
+        text: "ramul",
+        gloss: "twig",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ram",
+        gloss: "branch",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "petal",
+        gloss: "petal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sepal",
+        gloss: "sepal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stamin",
+        gloss: "thread",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pistill",
+        gloss: "pestle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bacc",
+        gloss: "berry",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "semin",
+        gloss: "seed",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sperm",
+        gloss: "seed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pollin",
+        gloss: "fine flour",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nectar",
+        gloss: "nectar",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "germin",
+        gloss: "sprout",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gemm",
+        gloss: "bud",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bulb",
+        gloss: "bulb",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tuber",
+        gloss: "swelling",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cortic",
+        gloss: "bark",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lign",
+        gloss: "wood",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "xyl",
+        gloss: "wood",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phloe",
+        gloss: "bark",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "dendr",
+        gloss: "tree",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "arbor",
+        gloss: "tree",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "silv",
+        gloss: "forest",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "frutic",
+        gloss: "shrub",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "herb",
+        gloss: "herb",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gramin",
+        gloss: "grass",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cyper",
+        gloss: "sedge",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "junc",
+        gloss: "rush",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "musc",
+        gloss: "moss",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bry",
+        gloss: "moss",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pterid",
+        gloss: "fern",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "filic",
+        gloss: "fern",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lichen",
+        gloss: "lichen",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "myc",
+        gloss: "fungus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fung",
+        gloss: "fungus",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "agaric",
+        gloss: "mushroom",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ros",
+        gloss: "rose",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lil",
+        gloss: "lily",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "irid",
+        gloss: "rainbow",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "viol",
+        gloss: "violet",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "orchid",
+        gloss: "orchid",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cact",
+        gloss: "cactus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pin",
+        gloss: "pine",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "abiet",
+        gloss: "fir",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "querc",
+        gloss: "oak",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "betul",
+        gloss: "birch",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "salic",
+        gloss: "willow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ulm",
+        gloss: "elm",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fraxin",
+        gloss: "ash",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "acer",
+        gloss: "maple",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tili",
+        gloss: "lime tree",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "laur",
+        gloss: "laurel",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "myrt",
+        gloss: "myrtle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "oliv",
+        gloss: "olive",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vit",
+        gloss: "vine",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "heder",
+        gloss: "ivy",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "trifol",
+        gloss: "clover",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "urtic",
+        gloss: "nettle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "thyrs",
+        gloss: "wand",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "panicul",
+        gloss: "tuft",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "umbell",
+        gloss: "little sunshade",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "corymb",
+        gloss: "cluster",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "racem",
+        gloss: "bunch of grapes",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cymos",
+        gloss: "sprout",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "bract",
+        gloss: "thin plate",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spath",
+        gloss: "blade",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aril",
+        gloss: "seed coat",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "drup",
+        gloss: "stone fruit",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pom",
+        gloss: "apple",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "siliqu",
+        gloss: "pod",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "legum",
+        gloss: "pulse",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "samar",
+        gloss: "elm seed",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "achen",
+        gloss: "seed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stolon",
+        gloss: "shoot",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phyt",
+        gloss: "plant",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "botan",
+        gloss: "pasture plant",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hort",
+        gloss: "garden",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "agr",
+        gloss: "field",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "prat",
+        gloss: "meadow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tricho",
+        gloss: "hair",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "calyc",
+        gloss: "cup",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "coroll",
+        gloss: "little crown",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gynoec",
+        gloss: "women's quarters",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "androec",
+        gloss: "men's quarters",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stigm",
+        gloss: "mark",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "styl",
+        gloss: "pillar",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "filament",
+        gloss: "thread",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pedicel",
+        gloss: "little foot",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pedunc",
+        gloss: "flower stalk",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scap",
+        gloss: "stalk",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tepal",
+        gloss: "tepal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "glum",
+        gloss: "husk",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lemm",
+        gloss: "husk",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pale",
+        gloss: "chaff",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ligul",
+        gloss: "strap",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "stipul",
+        gloss: "straw",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "petiol",
+        gloss: "little foot",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lamin",
+        gloss: "blade",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nerv",
+        gloss: "sinew",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "venul",
+        gloss: "little vein",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cuticul",
+        gloss: "little skin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "stomat",
+        gloss: "mouth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "meristem",
+        gloss: "divided",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cambi",
+        gloss: "exchange",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "medull",
+        gloss: "pith",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cotyl",
+        gloss: "cup",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "radicul",
+        gloss: "rootlet",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "plumul",
+        gloss: "little feather",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "endosperm",
+        gloss: "inner seed",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hil",
+        gloss: "scar",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "funicul",
+        gloss: "little cord",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "placent",
+        gloss: "flat cake",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "locul",
+        gloss: "compartment",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sept",
+        gloss: "partition",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "val",
+        gloss: "door leaf",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dehisc",
+        gloss: "gaping",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "strobil",
+        gloss: "pine cone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "con",
+        gloss: "cone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "squamul",
+        gloss: "little scale",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "frond",
+        gloss: "leafy branch",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sor",
+        gloss: "heap",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "indusi",
+        gloss: "tunic",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "prothall",
+        gloss: "early shoot",
+        origin: Some(Origin::Greek),
+    },
+];
+
+const MINERAL_ROOTS: &[Root] = &[
+    Root {
+        text: "petr",
+        gloss: "rock",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "crystall",
+        gloss: "crystal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "silic",
+        gloss: "flint",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "calc",
+        gloss: "lime",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gyps",
+        gloss: "gypsum",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "argill",
+        gloss: "clay",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chrys",
+        gloss: "gold",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ferr",
+        gloss: "iron",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sider",
+        gloss: "iron",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chalc",
+        gloss: "copper",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stann",
+        gloss: "tin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hydrarg",
+        gloss: "water silver",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "carbon",
+        gloss: "charcoal",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "graphit",
+        gloss: "writing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "adamant",
+        gloss: "unconquerable",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "smaragd",
+        gloss: "emerald",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sapphir",
+        gloss: "sapphire",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "rubin",
+        gloss: "ruby",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "beryll",
+        gloss: "beryl",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "topaz",
+        gloss: "topaz",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "opal",
+        gloss: "opal",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "achat",
+        gloss: "agate",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "jasp",
+        gloss: "jasper",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "amethyst",
+        gloss: "not drunken",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "granat",
+        gloss: "garnet",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "corund",
+        gloss: "corundum",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "zircon",
+        gloss: "zircon",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mic",
+        gloss: "crumb",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "talc",
+        gloss: "talc",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "schist",
+        gloss: "split",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "basalt",
+        gloss: "basalt",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "granit",
+        gloss: "grain",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "marmor",
+        gloss: "marble",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "obsidian",
+        gloss: "obsidian",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pumic",
+        gloss: "pumice",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tof",
+        gloss: "tuff",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aren",
+        gloss: "sand",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "psamm",
+        gloss: "sand",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sabul",
+        gloss: "coarse sand",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "glare",
+        gloss: "gravel",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lapid",
+        gloss: "stone",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sax",
+        gloss: "rock",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "rup",
+        gloss: "cliff",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spelae",
+        gloss: "cave",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "antr",
+        gloss: "cave",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stalact",
+        gloss: "dripping",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stalagm",
+        gloss: "drop",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "geod",
+        gloss: "earthy",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hyal",
+        gloss: "glass",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "prism",
+        gloss: "something sawn",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "rhomb",
+        gloss: "rhombus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cub",
+        gloss: "cube",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "metall",
+        gloss: "mine",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "magnet",
+        gloss: "lodestone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nitr",
+        gloss: "saltpetre",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "natr",
+        gloss: "soda",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "kal",
+        gloss: "potash",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "alumin",
+        gloss: "alum",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "halit",
+        gloss: "rock salt",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "borac",
+        gloss: "borax",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cinnabar",
+        gloss: "cinnabar",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "realgar",
+        gloss: "powder of the mine",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "galen",
+        gloss: "lead ore",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pyrit",
+        gloss: "fire stone",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "malachit",
+        gloss: "mallow",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "azur",
+        gloss: "azure",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lazul",
+        gloss: "lapis lazuli",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fluor",
+        gloss: "flow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "apatit",
+        gloss: "deceit",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "baryt",
+        gloss: "heavy",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "celestin",
+        gloss: "sky-blue",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dolomit",
+        gloss: "dolomite",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "magnesi",
+        gloss: "magnesia",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "serpentin",
+        gloss: "serpent",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "olivin",
+        gloss: "olive",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pyrox",
+        gloss: "fire stranger",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "quarz",
+        gloss: "quartz",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chalcedon",
+        gloss: "chalcedony",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "carneol",
+        gloss: "carnelian",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sard",
+        gloss: "sard",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "heliotrop",
+        gloss: "sun-turner",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tourmalin",
+        gloss: "tourmaline",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spinel",
+        gloss: "little thorn",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chrysoberyl",
+        gloss: "golden beryl",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cassiterit",
+        gloss: "tin",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "wolfram",
+        gloss: "tungsten",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "molybd",
+        gloss: "lead",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "bismut",
+        gloss: "bismuth",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "antimon",
+        gloss: "antimony",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "arsen",
+        gloss: "potent",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "selen",
+        gloss: "moon",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tellur",
+        gloss: "earth",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "platin",
+        gloss: "little silver",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "iridi",
+        gloss: "rainbow",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "osmi",
+        gloss: "smell",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pallad",
+        gloss: "Pallas",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "rhodi",
+        gloss: "rose",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nickel",
+        gloss: "goblin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cobalt",
+        gloss: "goblin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mangan",
+        gloss: "manganese",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "titan",
+        gloss: "Titan",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vanad",
+        gloss: "Vanadis",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "uran",
+        gloss: "sky",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "thori",
+        gloss: "Thor",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cer",
+        gloss: "Ceres",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "yttr",
+        gloss: "Ytterby",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scand",
+        gloss: "Scandinavia",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ochr",
+        gloss: "pale yellow",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "umbr",
+        gloss: "shade",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sienn",
+        gloss: "Siena",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cret",
+        gloss: "chalk",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "marg",
+        gloss: "marl",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lim",
+        gloss: "mud",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "loess",
+        gloss: "loose",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "till",
+        gloss: "boulder clay",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "brecci",
+        gloss: "rubble",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "conglomer",
+        gloss: "heaped together",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tuff",
+        gloss: "tuff",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lav",
+        gloss: "lava",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "magm",
+        gloss: "paste",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "scori",
+        gloss: "slag",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cinere",
+        gloss: "ash",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fumarol",
+        gloss: "smoke hole",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sinter",
+        gloss: "cinder",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "travertin",
+        gloss: "stone of Tibur",
+        origin: Some(Origin::Latin),
+    },
+];
+
+const CELESTIAL_ROOTS: &[Root] = &[
+    Root {
+        text: "aster",
+        gloss: "star",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "astr",
+        gloss: "star",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stell",
+        gloss: "star",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sol",
+        gloss: "sun",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "heli",
+        gloss: "sun",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lun",
+        gloss: "moon",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "planet",
+        gloss: "wanderer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "comet",
+        gloss: "long-haired",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "meteor",
+        gloss: "lofty",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cosm",
+        gloss: "order",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ouran",
+        gloss: "sky",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cael",
+        gloss: "heaven",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aether",
+        gloss: "upper air",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nebul",
+        gloss: "mist",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "galact",
+        gloss: "milk",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "orb",
+        gloss: "circle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cycl",
+        gloss: "circle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "zodiac",
+        gloss: "little animals",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "eclips",
+        gloss: "failing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "auror",
+        gloss: "dawn",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "eo",
+        gloss: "dawn",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hesper",
+        gloss: "evening",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vesper",
+        gloss: "evening",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "noct",
+        gloss: "night",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nyct",
+        gloss: "night",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "crepuscul",
+        gloss: "twilight",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phosphor",
+        gloss: "light-bringer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lumin",
+        gloss: "light",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "luc",
+        gloss: "light",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phot",
+        gloss: "light",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "radi",
+        gloss: "ray",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "spectr",
+        gloss: "apparition",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "polar",
+        gloss: "pole",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "bore",
+        gloss: "north",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "austr",
+        gloss: "south",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "zenit",
+        gloss: "path overhead",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "nadir",
+        gloss: "opposite",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "equinoct",
+        gloss: "equal night",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "solstiti",
+        gloss: "standing sun",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gnomon",
+        gloss: "indicator",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "horolog",
+        gloss: "hour-teller",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chron",
+        gloss: "time",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "orion",
+        gloss: "Orion",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "siri",
+        gloss: "scorching",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pleiad",
+        gloss: "Pleiades",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hyad",
+        gloss: "Hyades",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "dracon",
+        gloss: "dragon",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cygn",
+        gloss: "swan",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lyr",
+        gloss: "lyre",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aquil",
+        gloss: "eagle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "leon",
+        gloss: "lion",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "taur",
+        gloss: "bull",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scorpi",
+        gloss: "scorpion",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sagitt",
+        gloss: "arrow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "capricorn",
+        gloss: "goat-horned",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "gemin",
+        gloss: "twin",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cancr",
+        gloss: "crab",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mercur",
+        gloss: "Mercury",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vener",
+        gloss: "Venus",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mart",
+        gloss: "Mars",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "jov",
+        gloss: "Jupiter",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "saturn",
+        gloss: "Saturn",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "neptun",
+        gloss: "Neptune",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "plut",
+        gloss: "Pluto",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "gae",
+        gloss: "earth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nov",
+        gloss: "new",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fulg",
+        gloss: "flash",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "fulmin",
+        gloss: "thunderbolt",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "aer",
+        gloss: "air",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "nimb",
+        gloss: "cloud",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "arct",
+        gloss: "bear",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cassiop",
+        gloss: "Cassiopeia",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "androm",
+        gloss: "Andromeda",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "perse",
+        gloss: "Perseus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pegas",
+        gloss: "Pegasus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cephe",
+        gloss: "Cepheus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "centaur",
+        gloss: "centaur",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lup",
+        gloss: "wolf",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "corv",
+        gloss: "crow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "crater",
+        gloss: "cup",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phoenic",
+        gloss: "phoenix",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "pavon",
+        gloss: "peacock",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tucan",
+        gloss: "toucan",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "volant",
+        gloss: "flying",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "carin",
+        gloss: "keel",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "puppi",
+        gloss: "stern",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "pyxid",
+        gloss: "box",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "antli",
+        gloss: "pump",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sextant",
+        gloss: "sixth part",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "telescop",
+        gloss: "far-seeing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "microscop",
+        gloss: "small-seeing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "norm",
+        gloss: "carpenter's square",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "circin",
+        gloss: "compasses",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "triangul",
+        gloss: "triangle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ar",
+        gloss: "altar",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "column",
+        gloss: "column",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "libr",
+        gloss: "scales",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "virgin",
+        gloss: "maiden",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "coron",
+        gloss: "crown",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hercul",
+        gloss: "Hercules",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ophiuch",
+        gloss: "serpent-bearer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "serpent",
+        gloss: "serpent",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "scut",
+        gloss: "shield",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vulpecul",
+        gloss: "little fox",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "equul",
+        gloss: "little horse",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lacert",
+        gloss: "lizard",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "camelopard",
+        gloss: "giraffe",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lync",
+        gloss: "lynx",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "monocer",
+        gloss: "unicorn",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "eridan",
+        gloss: "Eridanus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fornac",
+        gloss: "furnace",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sculptor",
+        gloss: "sculptor",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "phaeth",
+        gloss: "shining",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hyperion",
+        gloss: "Hyperion",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "rhe",
+        gloss: "Rhea",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "iapet",
+        gloss: "Iapetus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "callist",
+        gloss: "most beautiful",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ganymed",
+        gloss: "Ganymede",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "europ",
+        gloss: "Europa",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "charon",
+        gloss: "Charon",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "penumbr",
+        gloss: "almost shadow",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "perihel",
+        gloss: "near the sun",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aphel",
+        gloss: "away from the sun",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "apog",
+        gloss: "away from the earth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "perig",
+        gloss: "near the earth",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "orbit",
+        gloss: "track",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "syzygi",
+        gloss: "yoked together",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "libration",
+        gloss: "balancing",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "parallax",
+        gloss: "alternation",
+        origin: Some(Origin::Greek),
+    },
+];
+
+const ACOUSTIC_ROOTS: &[Root] = &[
+    Root {
+        text: "phon",
+        gloss: "voice",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "son",
+        gloss: "sound",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ech",
+        gloss: "echo",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "reson",
+        gloss: "resounding",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chord",
+        gloss: "string",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "harmon",
+        gloss: "joining",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "melod",
+        gloss: "song",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "rhythm",
+        gloss: "measured flow",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "temp",
+        gloss: "time",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tact",
+        gloss: "touch",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "mus",
+        gloss: "Muse",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cant",
+        gloss: "song",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "canor",
+        gloss: "melodious",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "carmin",
+        gloss: "song",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "hymn",
+        gloss: "hymn",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "psalm",
+        gloss: "plucked song",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cithar",
+        gloss: "lyre",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "barbit",
+        gloss: "lyre",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aul",
+        gloss: "pipe",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "syring",
+        gloss: "panpipe",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "salping",
+        gloss: "trumpet",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tub",
+        gloss: "trumpet",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "corn",
+        gloss: "horn",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tympan",
+        gloss: "drum",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "crotal",
+        gloss: "rattle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sistr",
+        gloss: "rattle",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "cymbal",
+        gloss: "cymbal",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "campan",
+        gloss: "bell",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tintinn",
+        gloss: "jingling bell",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "organ",
+        gloss: "instrument",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "plectr",
+        gloss: "striker",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fid",
+        gloss: "string",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "psall",
+        gloss: "plucking",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "sonor",
+        gloss: "resounding",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "strid",
+        gloss: "creaking",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "clang",
+        gloss: "clang",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "crep",
+        gloss: "rattle",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "frem",
+        gloss: "roaring",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "murmur",
+        gloss: "murmur",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "susurr",
+        gloss: "whisper",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sibil",
+        gloss: "hiss",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "strep",
+        gloss: "din",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "bomb",
+        gloss: "booming",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tinn",
+        gloss: "ringing",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "chor",
+        gloss: "dance",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "orchestr",
+        gloss: "dancing place",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "dithyramb",
+        gloss: "hymn to Dionysus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "threnod",
+        gloss: "dirge",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "mel",
+        gloss: "song",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "diapas",
+        gloss: "through all",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "chromat",
+        gloss: "colour",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "enharmon",
+        gloss: "in tune",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "modul",
+        gloss: "measure",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cadenc",
+        gloss: "falling",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "canon",
+        gloss: "rule",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "voc",
+        gloss: "voice",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "lud",
+        gloss: "play",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "saltat",
+        gloss: "dancing",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "choreut",
+        gloss: "dancer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "puls",
+        gloss: "beat",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vibr",
+        gloss: "shaking",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "oscill",
+        gloss: "swinging",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "trem",
+        gloss: "trembling",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sonit",
+        gloss: "sound",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "audit",
+        gloss: "hearing",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cantic",
+        gloss: "song",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "ode",
+        gloss: "song",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aed",
+        gloss: "singer",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "rhapsod",
+        gloss: "stitcher of songs",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "epod",
+        gloss: "after-song",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "stroph",
+        gloss: "turning",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "antistroph",
+        gloss: "counter-turning",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "prosod",
+        gloss: "song added",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "iamb",
+        gloss: "iamb",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "trochae",
+        gloss: "running",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "spondae",
+        gloss: "libation",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "anapaest",
+        gloss: "struck back",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "caesur",
+        gloss: "cutting",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tetrachord",
+        gloss: "four strings",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "diatess",
+        gloss: "through four",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "diapent",
+        gloss: "through five",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "tripl",
+        gloss: "threefold",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tetr",
+        gloss: "four",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hemiol",
+        gloss: "one and a half",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "syncop",
+        gloss: "cutting short",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "fistul",
+        gloss: "pipe",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "calam",
+        gloss: "reed",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "psalter",
+        gloss: "psaltery",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "monochord",
+        gloss: "one string",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "hydraul",
+        gloss: "water pipe",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "celest",
+        gloss: "heavenly",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "xylophon",
+        gloss: "wood sound",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "vibraphon",
+        gloss: "vibrating sound",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "dulc",
+        gloss: "sweet",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "clavi",
+        gloss: "key",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "frequ",
+        gloss: "crowded",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "formant",
+        gloss: "forming",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "vibrat",
+        gloss: "vibrating",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "orphe",
+        gloss: "Orpheus",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "terpsichor",
+        gloss: "delight in dance",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "euterp",
+        gloss: "well-pleasing",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "calliop",
+        gloss: "beautiful voice",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "polyhymn",
+        gloss: "many hymns",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "marsy",
+        gloss: "Marsyas",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "neum",
+        gloss: "breath",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "plag",
+        gloss: "oblique",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "dor",
+        gloss: "Dorian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "phryg",
+        gloss: "Phrygian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "lydi",
+        gloss: "Lydian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "mixolyd",
+        gloss: "mixed Lydian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "aeoli",
+        gloss: "Aeolian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "ioni",
+        gloss: "Ionian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "locri",
+        gloss: "Locrian",
+        origin: Some(Origin::Greek),
+    },
+    Root {
+        text: "auscult",
+        gloss: "listening",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "sonant",
+        gloss: "sounding",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "tonitr",
+        gloss: "thunder",
+        origin: Some(Origin::Latin),
+    },
+    Root {
+        text: "cicad",
+        gloss: "cicada",
+        origin: Some(Origin::Latin),
+    },
+];
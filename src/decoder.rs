@@ -15,16 +15,148 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::config::Config;
-use crate::data::Morphemes;
-use crate::generator;
-use chrono::NaiveDate;
+use crate::config::Overrides;
+use crate::data::THEMES;
+use crate::key::Key;
+use crate::legacy;
+use crate::scheme::{self, Scheme};
+use chrono::{NaiveDate, NaiveTime};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    pub date: NaiveDate,
+    pub number: Option<u32>,
+    pub time: Option<NaiveTime>,
+    pub scheme: &'static str,
+    pub theme: Option<&'static str>,
+}
 
 pub fn decode(
     name: &str,
     salt: &str,
-    morphemes: &Morphemes,
-    config: &Config,
-) -> Option<(NaiveDate, u32)> {
-    generator::decode_name(name, salt, morphemes, config)
+    key: Option<&Key>,
+    scheme: &Scheme,
+) -> Result<Vec<Candidate>, String> {
+    let morphemes = scheme.morphemes();
+    let theme = scheme.theme.map(|theme| theme.id);
+
+    let numbered = scheme
+        .decode_candidates(name, salt, key, &morphemes, &scheme.config)?
+        .into_iter()
+        .map(|(date, number)| Candidate {
+            date,
+            number: Some(number),
+            time: None,
+            scheme: scheme.id,
+            theme,
+        });
+    let timed = scheme
+        .decode_timed_candidates(name, salt, key, &morphemes, &scheme.config)?
+        .into_iter()
+        .map(|datetime| Candidate {
+            date: datetime.date(),
+            number: None,
+            time: Some(datetime.time()),
+            scheme: scheme.id,
+            theme,
+        });
+
+    Ok(numbered.chain(timed).collect())
+}
+
+/// Tries every scheme that decodes instantly, newest first, with the
+/// built-in roots and then every theme unless the overrides pick tables.
+/// Schemes that cannot take the key or the overrides are skipped. A name
+/// that several schemes spell alike, such as one with nothing to inflect, is
+/// reported once under the newest. Moments from schemes that let a name
+/// stand for both kinds of tuple are most likely chance matches, so they are
+/// only reported when nothing more certain matches; `--scheme` lists them
+/// all.
+pub fn decode_any(
+    name: &str,
+    salt: &str,
+    key: Option<&Key>,
+    overrides: &Overrides,
+) -> Vec<Candidate> {
+    let themes = theme_overrides(overrides);
+
+    let mut found = scheme::SCHEMES
+        .iter()
+        .rev()
+        .filter(|scheme| scheme.decodes_instantly())
+        .filter(|scheme| key.is_none() || scheme.supports_keys())
+        .flat_map(|scheme| {
+            themes
+                .iter()
+                .filter_map(|overrides| scheme.with_overrides(overrides).ok())
+        })
+        .flat_map(|scheme| decode(name, salt, key, &scheme).unwrap_or_default())
+        .fold(Vec::new(), |mut found: Vec<Candidate>, candidate| {
+            let seen = found.iter().any(|c| {
+                (c.date, c.number, c.time) == (candidate.date, candidate.number, candidate.time)
+            });
+            if !seen {
+                found.push(candidate);
+            }
+            found
+        });
+
+    if found.iter().any(is_certain) {
+        found.retain(is_certain);
+    }
+    found
+}
+
+/// Whether a candidate is more than a chance match: a numbered work, or a
+/// moment from a scheme whose moments have names of their own.
+fn is_certain(candidate: &Candidate) -> bool {
+    candidate.number.is_some()
+        || scheme::find(candidate.scheme).is_some_and(Scheme::keeps_moments_apart)
+}
+
+/// Whether [`decode_any`] found no certain match, so the name may be from
+/// `v1`, which it does not search. Keyed names never are.
+pub fn needs_legacy(candidates: &[Candidate], key: Option<&Key>) -> bool {
+    key.is_none() && !candidates.iter().any(is_certain)
+}
+
+/// Searches `v1`, first the month either side of today and then, if nothing
+/// matches there, the whole century, which takes seconds rather than a
+/// millisecond. It cannot take overrides.
+pub fn decode_legacy(name: &str, salt: &str, overrides: &Overrides) -> Vec<Candidate> {
+    let Some(legacy) = scheme::find("v1").and_then(|legacy| legacy.with_overrides(overrides).ok())
+    else {
+        return Vec::new();
+    };
+
+    let recent = legacy::decode_recent(name, salt, &legacy.morphemes(), &legacy.config);
+    if recent.is_empty() {
+        return decode(name, salt, None, &legacy).unwrap_or_default();
+    }
+    recent
+        .into_iter()
+        .map(|(date, number)| Candidate {
+            date,
+            number: Some(number),
+            time: None,
+            scheme: legacy.id,
+            theme: None,
+        })
+        .collect()
+}
+
+/// `overrides` with the built-in roots and then with every theme, unless
+/// they pick tables already.
+pub(crate) fn theme_overrides(overrides: &Overrides) -> Vec<Overrides> {
+    if overrides.theme.is_some() || overrides.pack.is_some() {
+        return vec![overrides.clone()];
+    }
+
+    std::iter::once(None)
+        .chain(THEMES.iter().map(Some))
+        .map(|theme| Overrides {
+            theme,
+            ..overrides.clone()
+        })
+        .collect()
 }
@@ -0,0 +1,176 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Breaking names into their morphemes and saying what each one means.
+
+use crate::config::{Config, Overrides};
+use crate::data::{Gender, Morphemes, Origin, SpeciesDescriptor};
+use crate::decoder::theme_overrides;
+use crate::scheme;
+use crate::space::{NameIndices, NameSpace};
+
+/// Etymologies of `name`, one for each way `morphemes` spell it, such as
+/// "Cyano- (Gk. blue) + kines (Gk. movement) + -us (one; m.); insularis: of
+/// islands".
+pub fn explain(name: &str, morphemes: &Morphemes, config: &Config) -> Vec<String> {
+    let space = NameSpace::for_name(morphemes, config, name);
+    space
+        .parse(name)
+        .iter()
+        .map(|indices| etymology(&space, indices, name))
+        .collect()
+}
+
+/// Like [`explain`], with the tables of every scheme, newest first, and
+/// of every theme unless `overrides` pick tables. Etymologies that several
+/// tables share are listed once.
+pub fn explain_any(name: &str, overrides: &Overrides) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+
+    for scheme in scheme::SCHEMES.iter().rev() {
+        for overrides in theme_overrides(overrides) {
+            let Ok(scheme) = scheme.with_overrides(&overrides) else {
+                continue;
+            };
+            for etymology in explain(name, &scheme.morphemes(), &scheme.config) {
+                if !found.contains(&etymology) {
+                    found.push(etymology);
+                }
+            }
+        }
+    }
+
+    found
+}
+
+fn etymology(space: &NameSpace, indices: &NameIndices, name: &str) -> String {
+    let prefix = space.prefix(indices);
+    let root = space.root(indices);
+    let suffix = space.suffix(indices);
+
+    let gender = match Gender::of_suffix(suffix.text) {
+        Gender::Masculine => "m.",
+        Gender::Feminine => "f.",
+        Gender::Neuter => "n.",
+    };
+
+    let mut parts = vec![format!(
+        "{} + {} + -{} ({})",
+        glossed(
+            &format!("{}-", prefix.text),
+            Some(prefix.origin),
+            prefix.gloss
+        ),
+        glossed(root.text, root.origin, root.gloss),
+        suffix.text,
+        match sense(suffix.origin, suffix.gloss).as_str() {
+            "" => gender.to_string(),
+            sense => format!("{}; {}", sense, gender),
+        }
+    )];
+
+    let epithets = [Some(space.descriptor(indices)), space.subspecies(indices)];
+    for (word, descriptor) in name.split_whitespace().skip(1).zip(epithets) {
+        if let Some(descriptor) = descriptor {
+            parts.push(epithet(word, descriptor));
+        }
+    }
+
+    parts.join("; ")
+}
+
+/// `text` followed by its origin and gloss in brackets, if either is known.
+fn glossed(text: &str, origin: Option<Origin>, gloss: &str) -> String {
+    match sense(origin, gloss).as_str() {
+        "" => text.to_string(),
+        sense => format!("{} ({})", text, sense),
+    }
+}
+
+/// The origin and gloss of a morpheme, whichever are known.
+fn sense(origin: Option<Origin>, gloss: &str) -> String {
+    let origin = match origin {
+        Some(Origin::Greek) => "Gk.",
+        Some(Origin::Latin) => "L.",
+        None => "",
+    };
+
+    match (origin, gloss) {
+        ("", gloss) | (gloss, "") => gloss.to_string(),
+        (origin, gloss) => format!("{} {}", origin, gloss),
+    }
+}
+
+/// An epithet as spelled in the name, which may be inflected, and its gloss.
+fn epithet(word: &str, descriptor: &SpeciesDescriptor) -> String {
+    if descriptor.gloss.is_empty() {
+        word.to_string()
+    } else {
+        format!("{}: {}", word, descriptor.gloss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{GENUS_SUFFIXES, SPECIES_DESCRIPTORS, SUBSPECIES_DESCRIPTORS, THEMES};
+
+    #[test]
+    fn test_explain() {
+        let morphemes = Morphemes::new();
+        let config = Config::default();
+
+        assert_eq!(
+            explain("Cyanokinesus insularis", &morphemes, &config),
+            ["Cyano- (Gk. blue) + kines (Gk. movement) + -us (one; m.); insularis: of islands"]
+        );
+        assert_eq!(
+            explain("Microhymenum abundans", &morphemes, &config),
+            ["Micro- (Gk. small) + hymeni (Gk. membrane) + -um (thing; n.); abundans: abundant"]
+        );
+        assert!(explain("Cyanokinesus", &morphemes, &config).is_empty());
+    }
+
+    #[test]
+    fn test_explain_any_finds_themes() {
+        let etymologies = explain_any("Synonautiloma maritimum", &Overrides::default());
+
+        assert_eq!(
+            etymologies,
+            ["Syn- (Gk. together) + nautil (Gk. sailor) + -ma (Gk. result; n.); maritimum: of the sea"]
+        );
+    }
+
+    #[test]
+    fn test_every_morpheme_is_glossed() {
+        let morphemes = Morphemes::new();
+        let roots = THEMES.iter().flat_map(|theme| theme.roots);
+
+        for prefix in morphemes.prefixes {
+            assert!(!prefix.gloss.is_empty(), "{}", prefix.text);
+        }
+        for root in morphemes.roots.iter().chain(roots) {
+            assert!(!root.gloss.is_empty(), "{}", root.text);
+        }
+        for suffix in GENUS_SUFFIXES {
+            assert!(!suffix.gloss.is_empty(), "{}", suffix.text);
+        }
+        for descriptor in SPECIES_DESCRIPTORS.iter().chain(SUBSPECIES_DESCRIPTORS) {
+            assert!(!descriptor.gloss.is_empty(), "{}", descriptor.text);
+        }
+    }
+}
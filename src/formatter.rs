@@ -15,13 +15,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use chrono::{Datelike, NaiveDate};
+use crate::decoder::Candidate;
+use chrono::{Datelike, NaiveDate, NaiveTime};
 
 pub struct DecodedName {
     pub name: String,
     pub date: NaiveDate,
-    pub number: u32,
+    pub number: Option<u32>,
+    pub time: Option<NaiveTime>,
     pub salt: Option<String>,
+    pub scheme: Option<&'static str>,
+    pub theme: Option<&'static str>,
+    pub alternatives: Vec<Candidate>,
 }
 
 impl DecodedName {
@@ -35,31 +40,101 @@ impl DecodedName {
         Self {
             name,
             date,
-            number,
+            number: Some(number),
+            time: None,
             salt,
+            scheme: None,
+            theme: None,
+            alternatives: Vec::new(),
         }
     }
 
+    /// The decoding of `name` as its first candidate, with the others as
+    /// alternatives, or `None` if there are no candidates.
+    pub fn from_candidates(name: String, candidates: Vec<Candidate>, salt: &str) -> Option<Self> {
+        let mut candidates = candidates.into_iter();
+        let first = candidates.next()?;
+
+        Some(Self {
+            name,
+            date: first.date,
+            number: first.number,
+            time: first.time,
+            salt: (!salt.is_empty()).then(|| salt.to_string()),
+            scheme: Some(first.scheme),
+            theme: first.theme,
+            alternatives: candidates.collect(),
+        })
+    }
+
+    pub fn is_ambiguous(&self) -> bool {
+        !self.alternatives.is_empty()
+    }
+
     pub fn display(&self) {
         println!("\x1b[1;4m{}\x1b[0m", self.name);
 
+        self.display_candidate(self.date, self.number, self.time, self.scheme, self.theme);
+
+        if self.is_ambiguous() {
+            for candidate in &self.alternatives {
+                self.display_candidate(
+                    candidate.date,
+                    candidate.number,
+                    candidate.time,
+                    Some(candidate.scheme),
+                    candidate.theme,
+                );
+            }
+            println!(
+                "\x1b[33mAmbiguous: {} candidates share this name\x1b[0m",
+                self.alternatives.len() + 1
+            );
+        }
+    }
+
+    fn display_candidate(
+        &self,
+        date: NaiveDate,
+        number: Option<u32>,
+        time: Option<NaiveTime>,
+        scheme: Option<&str>,
+        theme: Option<&str>,
+    ) {
         let prefix = if self.salt.is_some() { "No." } else { "Op." };
 
-        let date_str = format_date_relative(self.date);
+        let date_str = format_moment_relative(date, time);
+        let scheme_str = match (scheme, theme) {
+            (Some(id), Some(theme)) => format!(" \x1b[2m({}, {})\x1b[0m", id, theme),
+            (Some(id), None) => format!(" \x1b[2m({})\x1b[0m", id),
+            (None, _) => String::new(),
+        };
+        let work = match number {
+            Some(number) => format!("{} {}, dated {}", prefix, number, date_str),
+            None => format!("Dated {}", date_str),
+        };
 
         if let Some(ref salt) = self.salt {
-            println!("[{}] {} {}, dated {}", salt, prefix, self.number, date_str);
+            println!("[{}] {}{}", salt, work, scheme_str);
         } else {
-            println!("{} {}, dated {}", prefix, self.number, date_str);
+            println!("{}{}", work, scheme_str);
         }
     }
 }
 
 pub fn format_date_relative(date: NaiveDate) -> String {
+    format_moment_relative(date, None)
+}
+
+/// Like [`format_date_relative`], with the time of day after the date.
+pub fn format_moment_relative(date: NaiveDate, time: Option<NaiveTime>) -> String {
     let today = chrono::Local::now().date_naive();
     let days_diff = (date - today).num_days();
 
-    let date_formatted = format!("{}.{}.{}", date.day(), date.month(), date.year());
+    let mut date_formatted = format!("{}.{}.{}", date.day(), date.month(), date.year());
+    if let Some(time) = time {
+        date_formatted.push_str(&time.format(" %H:%M").to_string());
+    }
 
     let relative = match days_diff {
         0 => "(today)".to_string(),
@@ -190,6 +265,52 @@ mod tests {
         assert_eq!(decoded.salt.as_ref().unwrap(), "mysalt");
     }
 
+    #[test]
+    fn test_ambiguity_flag() {
+        let first = Candidate {
+            date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+            number: Some(5),
+            time: None,
+            scheme: "v2",
+            theme: None,
+        };
+        let second = Candidate {
+            date: NaiveDate::from_ymd_opt(2019, 8, 6).unwrap(),
+            number: Some(44),
+            time: None,
+            scheme: "v1",
+            theme: None,
+        };
+
+        let decoded =
+            DecodedName::from_candidates("Test name".to_string(), vec![first.clone()], "").unwrap();
+        assert!(!decoded.is_ambiguous());
+        assert_eq!(decoded.scheme, Some("v2"));
+
+        let decoded =
+            DecodedName::from_candidates("Test name".to_string(), vec![first, second], "").unwrap();
+        assert!(decoded.is_ambiguous());
+        assert_eq!(decoded.alternatives[0].scheme, "v1");
+        assert!(DecodedName::from_candidates("Test name".to_string(), Vec::new(), "").is_none());
+    }
+
+    #[test]
+    fn test_timed_candidate() {
+        let candidate = Candidate {
+            date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+            number: None,
+            time: NaiveTime::from_hms_opt(14, 36, 0),
+            scheme: "v2",
+            theme: None,
+        };
+
+        let decoded =
+            DecodedName::from_candidates("Test name".to_string(), vec![candidate], "").unwrap();
+        assert_eq!(decoded.number, None);
+        assert_eq!(decoded.time, NaiveTime::from_hms_opt(14, 36, 0));
+        assert!(format_moment_relative(decoded.date, decoded.time).starts_with("15.1.2026 14:36 "));
+    }
+
     #[test]
     fn test_prefix_without_salt() {
         let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
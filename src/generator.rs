@@ -15,23 +15,131 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::config::Config;
-use crate::data::{Morpheme, Morphemes, Origin};
-use crate::pronounceability::pronounceability_score;
-use chrono::{Datelike, NaiveDate};
+use crate::config::{Config, Layout};
+use crate::data::{Morpheme, Morphemes, Origin, Suffix};
+use crate::key::Key;
+use crate::phonotactics::is_vowel;
+use crate::quality::Rejection;
+use crate::space::NameSpace;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
 
-const MAX_QUALITY_ATTEMPTS: u32 = 100;
+pub(crate) const MAX_QUALITY_ATTEMPTS: u32 = 100;
+/// Tuples need room for at least one retry, or a name the quality filter
+/// turned down would have nothing to fall back on.
+pub(crate) const MIN_QUALITY_ATTEMPTS: u32 = 2;
 
-fn encode_date_number(date: NaiveDate, number: u32) -> u64 {
-    let year = (date.year() - 2000) as u64;
-    let month = date.month() as u64;
-    let day = date.day() as u64;
-    let num = number as u64;
+fn epoch(config: &Config) -> NaiveDate {
+    NaiveDate::from_ymd_opt(config.epoch_year, 1, 1).expect("epoch_year out of range")
+}
+
+fn range_end(config: &Config) -> NaiveDate {
+    NaiveDate::from_ymd_opt(config.year_end, 12, 31).expect("year_end out of range")
+}
+
+fn days_in_range(config: &Config) -> u64 {
+    let start = NaiveDate::from_ymd_opt(config.year_start, 1, 1).expect("year_start out of range");
+    (range_end(config) - start).num_days() as u64 + 1
+}
+
+fn days_from_epoch(config: &Config) -> u64 {
+    (range_end(config) - epoch(config)).num_days() as u64 + 1
+}
+
+fn numbers_per_day(config: &Config) -> u64 {
+    (config.number_max - config.number_min) as u64 + 1
+}
+
+pub fn domain_size(config: &Config) -> u64 {
+    days_in_range(config) * numbers_per_day(config)
+}
+
+/// Orders the days of the range starting at the epoch: the epoch and the
+/// days after it come first, then the days before it, counting backwards.
+/// Extending a range in either direction therefore leaves the ordinals of
+/// the days it already held unchanged, as long as the epoch stays put.
+fn day_ordinal(date: NaiveDate, config: &Config) -> u64 {
+    let offset = (date - epoch(config)).num_days();
+    if offset >= 0 {
+        offset as u64
+    } else {
+        days_from_epoch(config) + (-offset - 1) as u64
+    }
+}
+
+fn date_from_ordinal(day: u64, config: &Config) -> Option<NaiveDate> {
+    let after = days_from_epoch(config);
+    if day < after {
+        epoch(config).checked_add_days(chrono::Days::new(day))
+    } else {
+        epoch(config).checked_sub_days(chrono::Days::new(day - after + 1))
+    }
+}
+
+/// Width of number block `block`: `numbers_per_block`, except that the last
+/// block ends at `number_max`.
+fn block_width(block: u64, config: &Config) -> u64 {
+    let per_block = config.numbers_per_block as u64;
+    per_block.min(numbers_per_day(config) - block * per_block)
+}
+
+/// Mixed-radix packing in blocks of numbers: the block is the most
+/// significant digit, then the day ordinal, then the number within the
+/// block. Every in-range pair maps to a distinct value in `[0, domain_size)`,
+/// and raising `number_max` only appends blocks, so the pairs already in
+/// range keep their values.
+pub fn encode_date_number(date: NaiveDate, number: u32, config: &Config) -> u64 {
+    let day = day_ordinal(date, config);
+    let num = (number - config.number_min) as u64;
+    let per_block = config.numbers_per_block as u64;
+    let block = num / per_block;
+
+    block * days_in_range(config) * per_block + day * block_width(block, config) + num % per_block
+}
+
+fn slots_per_day(config: &Config) -> u64 {
+    (24 * 60 / config.minutes_per_slot) as u64
+}
+
+pub fn time_domain_size(config: &Config) -> u64 {
+    days_in_range(config) * slots_per_day(config)
+}
+
+/// Packs the day ordinal with the time slot, which is truncated to
+/// `minutes_per_slot`.
+pub fn encode_date_time(datetime: NaiveDateTime, config: &Config) -> u64 {
+    let day = day_ordinal(datetime.date(), config);
+    let minute = (datetime.hour() * 60 + datetime.minute()) / config.minutes_per_slot;
 
-    year * 1_000_000 + month * 100_000 + day * 1_000 + num
+    day * slots_per_day(config) + minute as u64
 }
 
-fn hash_salt(salt: &str) -> u64 {
+pub fn decode_date_time(encoded: u64, config: &Config) -> Option<NaiveDateTime> {
+    if encoded >= time_domain_size(config) {
+        return None;
+    }
+
+    let date = date_from_ordinal(encoded / slots_per_day(config), config)?;
+    let minute = (encoded % slots_per_day(config)) as u32 * config.minutes_per_slot;
+
+    date.and_hms_opt(minute / 60, minute % 60, 0)
+}
+
+pub fn decode_date_number(encoded: u64, config: &Config) -> Option<(NaiveDate, u32)> {
+    if encoded >= domain_size(config) {
+        return None;
+    }
+
+    let per_block = config.numbers_per_block as u64;
+    let block = encoded / (days_in_range(config) * per_block);
+    let local = encoded % (days_in_range(config) * per_block);
+    let width = block_width(block, config);
+    let num = block * per_block + local % width;
+
+    let date = date_from_ordinal(local / width, config)?;
+    Some((date, num as u32 + config.number_min))
+}
+
+pub(crate) fn hash_salt(salt: &str) -> u64 {
     if salt.is_empty() {
         0x123456789abcdef0
     } else {
@@ -41,7 +149,7 @@ fn hash_salt(salt: &str) -> u64 {
     }
 }
 
-fn feistel_round(value: u64, key: u64) -> u64 {
+pub(crate) fn feistel_round(value: u64, key: u64) -> u64 {
     let mut h = value.wrapping_add(key);
     h = h.wrapping_mul(0x517cc1b727220a95);
     h ^= h >> 33;
@@ -50,32 +158,126 @@ fn feistel_round(value: u64, key: u64) -> u64 {
     h & 0xFFFFFFFF
 }
 
-fn permute(x: u64, salt_hash: u64) -> u64 {
-    const ROUNDS: usize = 4;
-    const MASK: u64 = 0xFFFFFFFF;
+const ROUNDS: usize = 4;
+
+/// The Feistel round function, keyed publicly by the type hash or privately
+/// by a [`Key`].
+pub(crate) enum Rounds {
+    Public(u64),
+    Private(Key),
+}
+
+impl Rounds {
+    pub(crate) fn new(salt: &str, key: Option<&Key>) -> Self {
+        match key {
+            Some(key) => Rounds::Private(key.derive(salt.as_bytes())),
+            None => Rounds::Public(hash_salt(salt)),
+        }
+    }
+
+    /// Rounds for the tables of a pack, unrelated to those of the built-in
+    /// tables or of other packs.
+    fn for_tables(self, morphemes: &Morphemes) -> Self {
+        match (morphemes.pack_hash, self) {
+            (None, rounds) => rounds,
+            (Some(hash), Rounds::Public(salt_hash)) => Rounds::Public(salt_hash ^ hash),
+            (Some(hash), Rounds::Private(key)) => Rounds::Private(key.derive(&hash.to_le_bytes())),
+        }
+    }
+
+    /// Rounds for the time-slot domain, unrelated to the numbered ones.
+    fn for_times(&self) -> Self {
+        match self {
+            Rounds::Public(salt_hash) => Rounds::Public(salt_hash ^ 0x7f4a7c159e3779b9),
+            Rounds::Private(key) => Rounds::Private(key.derive(b"time")),
+        }
+    }
+
+    fn apply(&self, round: usize, value: u64) -> u64 {
+        match self {
+            Rounds::Public(salt_hash) => {
+                feistel_round(value, salt_hash.wrapping_mul(round as u64 + 1))
+            }
+            Rounds::Private(key) => {
+                let mut input = [0u8; 9];
+                input[0] = round as u8;
+                input[1..].copy_from_slice(&value.to_le_bytes());
+                key.hash(&input)
+            }
+        }
+    }
+}
+
+fn permutation_half_bits(size: u64) -> u32 {
+    let bits = u64::BITS - (size - 1).leading_zeros();
+    bits.div_ceil(2)
+}
+
+fn permute(x: u64, rounds: &Rounds, half_bits: u32) -> u64 {
+    let mask = (1u64 << half_bits) - 1;
 
-    let mut left = (x >> 32) & MASK;
-    let mut right = x & MASK;
+    let mut left = (x >> half_bits) & mask;
+    let mut right = x & mask;
 
     for round in 0..ROUNDS {
-        let round_key = salt_hash.wrapping_mul(round as u64 + 1);
-        let f_output = feistel_round(right, round_key);
+        let f_output = rounds.apply(round, right) & mask;
         (left, right) = (right, left ^ f_output);
     }
 
-    (left << 32) | right
+    (left << half_bits) | right
 }
 
-fn is_vowel(c: char) -> bool {
-    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+fn unpermute(x: u64, rounds: &Rounds, half_bits: u32) -> u64 {
+    let mask = (1u64 << half_bits) - 1;
+
+    let mut left = (x >> half_bits) & mask;
+    let mut right = x & mask;
+
+    for round in (0..ROUNDS).rev() {
+        let f_output = rounds.apply(round, left) & mask;
+        (left, right) = (right ^ f_output, left);
+    }
+
+    (left << half_bits) | right
+}
+
+/// Restricts the Feistel permutation to `[0, size)` by cycle-walking: a
+/// value that lands outside the range is permuted again until it falls
+/// inside. The network is at most four times wider than the range, so the
+/// walk is short on average.
+fn permute_within(x: u64, size: u64, rounds: &Rounds) -> u64 {
+    let half_bits = permutation_half_bits(size);
+
+    let mut permuted = permute(x, rounds, half_bits);
+    while permuted >= size {
+        permuted = permute(permuted, rounds, half_bits);
+    }
+    permuted
+}
+
+fn unpermute_within(x: u64, size: u64, rounds: &Rounds) -> u64 {
+    let half_bits = permutation_half_bits(size);
+
+    let mut unpermuted = unpermute(x, rounds, half_bits);
+    while unpermuted >= size {
+        unpermuted = unpermute(unpermuted, rounds, half_bits);
+    }
+    unpermuted
+}
+
+/// Whether `c` elides before a vowel and takes no connector when morphemes
+/// are joined. Every name depends on joining, which has always treated y
+/// as a consonant there: Poly- takes a connector, and -y is never elided.
+pub(crate) fn joins_as_vowel(c: char) -> bool {
+    is_vowel(c) && !c.eq_ignore_ascii_case(&'y')
 }
 
 fn starts_with_vowel(s: &str) -> bool {
-    s.chars().next().is_some_and(is_vowel)
+    s.chars().next().is_some_and(joins_as_vowel)
 }
 
 fn ends_with_vowel(s: &str) -> bool {
-    s.chars().last().is_some_and(is_vowel)
+    s.chars().last().is_some_and(joins_as_vowel)
 }
 
 fn capitalize_first(s: &str) -> String {
@@ -86,29 +288,43 @@ fn capitalize_first(s: &str) -> String {
     }
 }
 
-fn get_safe_suffixes(morphemes: &Morphemes) -> Vec<&'static str> {
+fn connector(origin: Origin) -> &'static str {
+    match origin {
+        Origin::Greek => "o",
+        Origin::Latin => "i",
+    }
+}
+
+pub(crate) fn get_safe_suffixes<'a>(morphemes: &Morphemes<'a>) -> Vec<Suffix<'a>> {
     morphemes
         .genus_suffixes
         .iter()
-        .filter(|&&s| !matches!(s, "yx" | "ix" | "ax"))
+        .filter(|s| !matches!(s.text, "yx" | "ix" | "ax"))
         .copied()
         .collect()
 }
 
-fn assemble_genus(prefix: &Morpheme, root: &str, suffix: &str) -> String {
+pub(crate) fn assemble_genus(prefix: &Morpheme, root: &str, suffix: &str) -> String {
+    assemble_genus_joined(prefix, root, prefix.origin, suffix)
+}
+
+/// Like [`assemble_genus`], but each morpheme takes the connector of its
+/// own origin: the prefix's before the root, and `root_origin`'s before the
+/// suffix.
+pub(crate) fn assemble_genus_joined(
+    prefix: &Morpheme,
+    root: &str,
+    root_origin: Origin,
+    suffix: &str,
+) -> String {
     let p = prefix.text.trim_end_matches('-');
     let r = root.trim_start_matches('-').trim_end_matches('-');
     let s = suffix.trim_start_matches('-');
 
-    let connector = match prefix.origin {
-        Origin::Greek => "o",
-        Origin::Latin => "i",
-    };
-
     let stem = if ends_with_vowel(p) || starts_with_vowel(r) {
         format!("{}{}", p, r)
     } else {
-        format!("{}{}{}", p, connector, r)
+        format!("{}{}{}", p, connector(prefix.origin), r)
     };
 
     let result = if starts_with_vowel(s) {
@@ -120,150 +336,581 @@ fn assemble_genus(prefix: &Morpheme, root: &str, suffix: &str) -> String {
     } else if ends_with_vowel(&stem) {
         format!("{}{}", stem, s)
     } else {
-        format!("{}{}{}", stem, connector, s)
+        format!("{}{}{}", stem, connector(root_origin), s)
     };
 
     capitalize_first(&result.to_lowercase())
 }
 
-fn is_name_acceptable(genus: &str, config: &Config) -> bool {
-    if genus.len() > config.max_genus_length {
-        return false;
+/// How the quality filter's tries for the tuples of a domain are spread
+/// over a name space; see [`Layout`].
+#[derive(Copy, Clone, Debug)]
+struct Strides {
+    stride: u64,
+    attempts: u64,
+    /// The part of every stride the tuples use, `[offset, offset + width)`.
+    offset: u64,
+    width: u64,
+}
+
+/// Which kind of tuple a name stands for.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Kind {
+    Numbered,
+    Timed,
+}
+
+impl Strides {
+    fn new(space: &NameSpace, domain: u64, config: &Config, kind: Kind) -> Self {
+        let (stride, attempts) = match config.layout {
+            Layout::Domain => (
+                domain,
+                (space.size() / domain).min(MAX_QUALITY_ATTEMPTS as u64),
+            ),
+            Layout::Strided { attempts } => (space.size() / attempts as u64, attempts as u64),
+        };
+
+        // Under a strided layout numbered pairs and moments share the
+        // trinomials and their permutation, so moments keep to the last
+        // eighth of every stride and a name never stands for both.
+        let moments = stride / 8;
+        let (offset, width) = match (config.layout, kind) {
+            (Layout::Strided { .. }, Kind::Numbered) if space.is_trinomial() => {
+                (0, stride - moments)
+            }
+            (Layout::Strided { .. }, Kind::Timed) => (stride - moments, moments),
+            _ => (0, stride),
+        };
+
+        Self {
+            stride,
+            attempts,
+            offset,
+            width,
+        }
+    }
+
+    /// Whether every tuple of the domain gets enough tries.
+    fn hold(&self, domain: u64) -> bool {
+        self.attempts >= MIN_QUALITY_ATTEMPTS as u64 && domain <= self.width
     }
 
-    let score = pronounceability_score(genus);
-    score >= config.min_pronounceability_score
+    /// The input that try `attempt` permutes for the tuple valued `value`.
+    fn input(&self, attempt: u64, value: u64) -> u64 {
+        attempt * self.stride + self.offset + value
+    }
+}
+
+/// Where a tuple is named: the space, how the tries are spread over it and
+/// the value the first try permutes.
+struct Placement<'a> {
+    space: NameSpace<'a>,
+    strides: Strides,
+    value: u64,
 }
 
-fn generate_name_internal(
+/// How many of the first packed pairs get binomials under a strided layout:
+/// the first block of numbers, as far as the binomial strides reach. The
+/// rest are named with trinomials, whose value is the packed pair itself.
+fn binomial_share(binomials: &NameSpace, config: &Config) -> u64 {
+    let first_block = days_in_range(config) * block_width(0, config);
+    first_block.min(Strides::new(binomials, first_block, config, Kind::Numbered).width)
+}
+
+/// Finds where the pair packed as `encoded` is named, or explains why the
+/// ranges leave it no room.
+fn place_number<'a>(
     encoded: u64,
-    salt_hash: u64,
-    morphemes: &Morphemes,
-    _config: &Config,
-) -> String {
-    let permuted = permute(encoded, salt_hash);
+    morphemes: &'a Morphemes,
+    config: &Config,
+) -> Result<Placement<'a>, String> {
+    let strided = matches!(config.layout, Layout::Strided { .. });
+
+    if strided && !config.trinomials {
+        let binomials = NameSpace::configured(morphemes, config, false);
+        let share = binomial_share(&binomials, config);
+        if encoded < share {
+            return Ok(Placement {
+                strides: Strides::new(&binomials, share, config, Kind::Numbered),
+                space: binomials,
+                value: encoded,
+            });
+        }
+        if morphemes.subspecies_descriptors.is_empty() {
+            return Err(format!(
+                "The configured ranges hold {} (date, number) pairs but there are only {} \
+                 binomials with {} tries each, and the morpheme tables have no subspecies \
+                 descriptors for the trinomials the rest need; narrow the years or the numbers",
+                domain_size(config),
+                share,
+                Strides::new(&binomials, share, config, Kind::Numbered).attempts
+            ));
+        }
+    } else if config.trinomials && morphemes.subspecies_descriptors.is_empty() {
+        return Err(
+            "The morpheme tables have no subspecies descriptors for trinomials".to_string(),
+        );
+    }
 
-    let genus_seed = permuted & 0xFFFFFFFF;
-    let species_seed = (permuted >> 32) & 0xFFFFFFFF;
+    let space = NameSpace::configured(morphemes, config, config.trinomials || strided);
+    let domain = domain_size(config);
+    let strides = Strides::new(&space, domain, config, Kind::Numbered);
+    if !strides.hold(domain) {
+        return Err(format!(
+            "The configured ranges hold {} (date, number) pairs but there is only room for {}{}; \
+             narrow the years or the numbers{}",
+            domain,
+            capacity(&space, config, Kind::Numbered),
+            per_try(config),
+            if space.is_trinomial() {
+                ""
+            } else {
+                ", or use trinomials"
+            }
+        ));
+    }
 
-    let safe_suffixes = get_safe_suffixes(morphemes);
+    Ok(Placement {
+        space,
+        strides,
+        value: encoded,
+    })
+}
 
-    let prefix_idx = (genus_seed % morphemes.prefixes.len() as u64) as usize;
-    let root_idx = ((genus_seed >> 8) % morphemes.roots.len() as u64) as usize;
-    let suffix_idx = ((genus_seed >> 16) % safe_suffixes.len() as u64) as usize;
+/// Finds where the time slot packed as `encoded` is named.
+fn place_moment<'a>(
+    encoded: u64,
+    morphemes: &'a Morphemes,
+    config: &Config,
+) -> Result<Placement<'a>, String> {
+    if moments_are_trinomials(config) && morphemes.subspecies_descriptors.is_empty() {
+        return Err(
+            "The morpheme tables have no subspecies descriptors for naming moments".to_string(),
+        );
+    }
 
-    let prefix = &morphemes.prefixes[prefix_idx];
-    let root = morphemes.roots[root_idx];
-    let genus_suffix = safe_suffixes[suffix_idx];
+    let space = moment_space(morphemes, config);
+    let domain = time_domain_size(config);
+    let strides = Strides::new(&space, domain, config, Kind::Timed);
+    if !strides.hold(domain) {
+        return Err(format!(
+            "The configured range holds {} {}-minute time slots but there is only room for {}{}",
+            domain,
+            config.minutes_per_slot,
+            capacity(&space, config, Kind::Timed),
+            per_try(config)
+        ));
+    }
 
-    let genus = assemble_genus(prefix, root, genus_suffix);
+    Ok(Placement {
+        space,
+        strides,
+        value: encoded,
+    })
+}
 
-    let category = prefix.category;
+/// Names the tuple with its first acceptable try, or fails if the quality
+/// filter turned every try down: issuing a rejected name instead could
+/// hand out a blocked word.
+fn generate_in_space(
+    placement: &Placement,
+    rounds: &Rounds,
+    config: &Config,
+) -> Result<String, String> {
+    let Placement {
+        space,
+        strides,
+        value,
+    } = placement;
 
-    let suitable_descriptors: Vec<&str> = morphemes
-        .species_descriptors
-        .iter()
-        .filter(|d| d.category.is_none() || d.category == Some(category))
-        .map(|d| d.text)
-        .collect();
+    for attempt in 0..strides.attempts {
+        let permuted = permute_within(strides.input(attempt, *value), space.size(), rounds);
+        let indices = space.indices(permuted);
 
-    let descriptor_idx = (species_seed % suitable_descriptors.len() as u64) as usize;
-    let species = suitable_descriptors[descriptor_idx];
+        if space.is_acceptable(&indices, config) {
+            return Ok(space.name(&indices));
+        }
+    }
 
-    format!("{} {}", genus, species)
+    Err(format!(
+        "The quality filter turned down all {} tries for this tuple; see them with \
+         --why-rejected",
+        strides.attempts
+    ))
 }
 
-pub fn generate_name(
-    date: NaiveDate,
-    number: u32,
-    salt: &str,
-    morphemes: &Morphemes,
+/// The names the quality filter turned down for a tuple before the one it
+/// issues, each with the rules it broke.
+fn rejected_in_space(
+    placement: &Placement,
+    rounds: &Rounds,
     config: &Config,
-) -> String {
-    let base_encoded = encode_date_number(date, number);
-    let salt_hash = hash_salt(salt);
-
-    for quality_offset in 0..MAX_QUALITY_ATTEMPTS {
-        let encoded = if quality_offset == 0 {
-            base_encoded
-        } else {
-            base_encoded
-                .wrapping_mul(0x9e3779b97f4a7c15)
-                .wrapping_add(quality_offset as u64)
-        };
+) -> Vec<(String, Vec<Rejection>)> {
+    let Placement {
+        space,
+        strides,
+        value,
+    } = placement;
+    let mut rejected = Vec::new();
 
-        let name = generate_name_internal(encoded, salt_hash, morphemes, config);
-        let genus = name.split_whitespace().next().unwrap_or("");
+    for attempt in 0..strides.attempts {
+        let permuted = permute_within(strides.input(attempt, *value), space.size(), rounds);
+        let indices = space.indices(permuted);
+        let rejections = space.rejections(&indices, config);
 
-        if is_name_acceptable(genus, config) {
-            return name;
+        if rejections.is_empty() {
+            break;
         }
+        rejected.push((space.name(&indices), rejections));
     }
 
-    generate_name_internal(base_encoded, salt_hash, morphemes, config)
+    rejected
 }
 
-pub fn decode_name(
+/// Recovers the values in `[0, count)` that `space` names `name` with
+/// without searching the calendar: the name is parsed back into table
+/// indices, the permutation is run backwards and the quality retry is
+/// undone.
+fn decode_in_space(
     name: &str,
-    salt: &str,
-    morphemes: &Morphemes,
+    count: u64,
+    kind: Kind,
+    rounds: &Rounds,
+    space: NameSpace,
     config: &Config,
-) -> Option<(NaiveDate, u32)> {
-    let now = chrono::Local::now().date_naive();
+) -> Vec<u64> {
+    let mut found = Vec::new();
 
-    for num in config.number_min..=config.number_max {
-        if generate_name(now, num, salt, morphemes, config) == name {
-            return Some((now, num));
-        }
+    // Names issued before ranges without room for a retry were refused
+    // stay decodable, so a single try is enough here.
+    let strides = Strides::new(&space, count, config, kind);
+    if strides.attempts == 0 || count > strides.width {
+        return found;
     }
 
-    for offset in 1..=30 {
-        if let Some(date) = now.checked_add_signed(chrono::Duration::days(offset)) {
-            for num in config.number_min..=config.number_max {
-                if generate_name(date, num, salt, morphemes, config) == name {
-                    return Some((date, num));
-                }
-            }
+    let mut placement = Placement {
+        space,
+        strides,
+        value: 0,
+    };
+    for indices in placement.space.parse(name) {
+        let encoded = unpermute_within(
+            placement.space.rank(&indices),
+            placement.space.size(),
+            rounds,
+        );
+        let (attempt, within) = (encoded / strides.stride, encoded % strides.stride);
+        if attempt >= strides.attempts || within < strides.offset {
+            continue;
         }
-        if let Some(date) = now.checked_sub_signed(chrono::Duration::days(offset)) {
-            for num in config.number_min..=config.number_max {
-                if generate_name(date, num, salt, morphemes, config) == name {
-                    return Some((date, num));
-                }
-            }
+
+        let value = within - strides.offset;
+        if value >= count || found.contains(&value) {
+            continue;
         }
-    }
 
-    for year in config.year_start..=config.year_end {
-        for month in 1..=12 {
-            let days = days_in_month(year, month);
-            for day in 1..=days {
-                if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
-                    for num in config.number_min..=config.number_max {
-                        if generate_name(date, num, salt, morphemes, config) == name {
-                            return Some((date, num));
-                        }
-                    }
-                }
-            }
+        // The name is only issued if no earlier try for the same tuple was
+        // acceptable, which regenerating the tuple checks.
+        placement.value = value;
+        if generate_in_space(&placement, rounds, config).is_ok_and(|issued| issued == name) {
+            found.push(value);
         }
     }
 
-    None
+    found
+}
+
+/// Orders candidates by preference: dates within a month of today first,
+/// nearest first, then the rest chronologically.
+pub(crate) fn prefer_recent<T: Copy + Ord>(
+    candidates: &mut [T],
+    date_of: impl Fn(&T) -> NaiveDate,
+) {
+    let today = chrono::Local::now().date_naive();
+    candidates.sort_by_key(|candidate| {
+        let distance = (date_of(candidate) - today).num_days();
+        if distance.abs() <= 30 {
+            (0, distance.abs(), distance < 0, *candidate)
+        } else {
+            (1, 0, false, *candidate)
+        }
+    });
+}
+
+pub fn check_capacity(morphemes: &Morphemes, config: &Config) -> Result<(), String> {
+    // The last pair is the one most likely to need a trinomial.
+    place_number(domain_size(config) - 1, morphemes, config).map(|_| ())
+}
+
+pub fn check_time_capacity(morphemes: &Morphemes, config: &Config) -> Result<(), String> {
+    place_moment(0, morphemes, config).map(|_| ())
+}
+
+/// How many tuples of one kind `space` has room for.
+fn capacity(space: &NameSpace, config: &Config, kind: Kind) -> u64 {
+    match config.layout {
+        Layout::Domain => space.size() / MIN_QUALITY_ATTEMPTS as u64,
+        Layout::Strided { .. } => Strides::new(space, 1, config, kind).width,
+    }
 }
 
-fn days_in_month(year: i32, month: u32) -> u32 {
-    match month {
-        2 => {
-            if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
-                29
+/// How many (date, number) pairs the name spaces have room for under
+/// `config`.
+pub(crate) fn number_capacity(morphemes: &Morphemes, config: &Config) -> u64 {
+    match config.layout {
+        Layout::Strided { .. } if !config.trinomials => {
+            let share = binomial_share(&NameSpace::configured(morphemes, config, false), config);
+            if morphemes.subspecies_descriptors.is_empty() {
+                share
             } else {
-                28
+                share.max(capacity(
+                    &NameSpace::configured(morphemes, config, true),
+                    config,
+                    Kind::Numbered,
+                ))
             }
         }
-        4 | 6 | 9 | 11 => 30,
-        _ => 31,
+        _ => capacity(
+            &NameSpace::for_config(morphemes, config),
+            config,
+            Kind::Numbered,
+        ),
+    }
+}
+
+/// How many time slots the moment space has room for under `config`.
+pub(crate) fn time_capacity(morphemes: &Morphemes, config: &Config) -> u64 {
+    capacity(&moment_space(morphemes, config), config, Kind::Timed)
+}
+
+fn per_try(config: &Config) -> String {
+    match config.layout {
+        Layout::Domain => format!(" with at least {} tries each", MIN_QUALITY_ATTEMPTS),
+        Layout::Strided { attempts } => format!(" with {} tries each", attempts),
+    }
+}
+
+/// The permutation moments are named with: the numbered one under a strided
+/// layout, which gives them a lane of its own, and one of their own
+/// otherwise.
+fn moment_rounds(salt: &str, key: Option<&Key>, morphemes: &Morphemes, config: &Config) -> Rounds {
+    let rounds = Rounds::new(salt, key).for_tables(morphemes);
+    match config.layout {
+        Layout::Domain => rounds.for_times(),
+        Layout::Strided { .. } => rounds,
+    }
+}
+
+fn moments_are_trinomials(config: &Config) -> bool {
+    config.trinomials || matches!(config.layout, Layout::Strided { .. })
+}
+
+/// The space moments are named from, which is the trinomial one whenever
+/// the layout asks for it.
+pub(crate) fn moment_space<'a>(morphemes: &'a Morphemes, config: &Config) -> NameSpace<'a> {
+    NameSpace::configured(morphemes, config, moments_are_trinomials(config))
+}
+
+/// Checks that `number` and the year of `date` lie in the configured ranges,
+/// the only tuples the packing has room for.
+pub fn check_tuple(date: NaiveDate, number: u32, config: &Config) -> Result<(), String> {
+    check_number(number, config)?;
+    check_date(date, config)
+}
+
+pub fn check_number(number: u32, config: &Config) -> Result<(), String> {
+    if !(config.number_min..=config.number_max).contains(&number) {
+        return Err(format!(
+            "Number {} is out of range [{}, {}] (widen it with --numbers)",
+            number, config.number_min, config.number_max
+        ));
     }
+    Ok(())
+}
+
+pub fn check_date(date: NaiveDate, config: &Config) -> Result<(), String> {
+    if date.year() < config.year_start || date.year() > config.year_end {
+        return Err(format!(
+            "Date year {} is out of range [{}, {}]",
+            date.year(),
+            config.year_start,
+            config.year_end
+        ));
+    }
+    Ok(())
+}
+
+pub fn generate_name(
+    date: NaiveDate,
+    number: u32,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Result<String, String> {
+    generate_name_keyed(date, number, salt, None, morphemes, config)
+}
+
+/// Like [`generate_name`], but in the private namespace of `key` when one
+/// is given.
+pub fn generate_name_keyed(
+    date: NaiveDate,
+    number: u32,
+    salt: &str,
+    key: Option<&Key>,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Result<String, String> {
+    check_tuple(date, number, config)?;
+    check_capacity(morphemes, config)?;
+    let placement = place_number(encode_date_number(date, number, config), morphemes, config)?;
+
+    generate_in_space(
+        &placement,
+        &Rounds::new(salt, key).for_tables(morphemes),
+        config,
+    )
+}
+
+/// The names [`generate_name_keyed`] passed over for a tuple, and why.
+pub fn rejected_names(
+    date: NaiveDate,
+    number: u32,
+    salt: &str,
+    key: Option<&Key>,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Result<Vec<(String, Vec<Rejection>)>, String> {
+    check_tuple(date, number, config)?;
+    check_capacity(morphemes, config)?;
+    let placement = place_number(encode_date_number(date, number, config), morphemes, config)?;
+
+    Ok(rejected_in_space(
+        &placement,
+        &Rounds::new(salt, key).for_tables(morphemes),
+        config,
+    ))
+}
+
+/// Names a moment instead of a numbered work. Times fall into slots of
+/// `minutes_per_slot`, which have a permutation or a lane of their own, so a
+/// timed name never stands for the same tuple as a numbered one.
+pub fn generate_timed_name(
+    datetime: NaiveDateTime,
+    salt: &str,
+    key: Option<&Key>,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Result<String, String> {
+    check_date(datetime.date(), config)?;
+    let placement = place_moment(encode_date_time(datetime, config), morphemes, config)?;
+
+    generate_in_space(
+        &placement,
+        &moment_rounds(salt, key, morphemes, config),
+        config,
+    )
+}
+
+/// The names [`generate_timed_name`] passed over for a time slot, and why.
+pub fn rejected_timed_names(
+    datetime: NaiveDateTime,
+    salt: &str,
+    key: Option<&Key>,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Result<Vec<(String, Vec<Rejection>)>, String> {
+    check_date(datetime.date(), config)?;
+    let placement = place_moment(encode_date_time(datetime, config), morphemes, config)?;
+
+    Ok(rejected_in_space(
+        &placement,
+        &moment_rounds(salt, key, morphemes, config),
+        config,
+    ))
+}
+
+/// Recovers every (date, number) pair that generates `name`, most likely
+/// first.
+pub fn decode_candidates(
+    name: &str,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Vec<(NaiveDate, u32)> {
+    decode_candidates_keyed(name, salt, None, morphemes, config)
+}
+
+pub fn decode_candidates_keyed(
+    name: &str,
+    salt: &str,
+    key: Option<&Key>,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Vec<(NaiveDate, u32)> {
+    let space = NameSpace::for_name(morphemes, config, name);
+    let rounds = Rounds::new(salt, key).for_tables(morphemes);
+
+    // Under a strided layout binomials only name the first pairs, while
+    // trinomials name every pair whatever the trinomial setting.
+    let count = match config.layout {
+        Layout::Strided { .. } if !space.is_trinomial() => {
+            binomial_share(&space, config).min(domain_size(config))
+        }
+        _ => domain_size(config),
+    };
+
+    let mut candidates: Vec<(NaiveDate, u32)> =
+        decode_in_space(name, count, Kind::Numbered, &rounds, space, config)
+            .into_iter()
+            .filter_map(|encoded| decode_date_number(encoded, config))
+            .collect();
+
+    prefer_recent(&mut candidates, |&(date, _)| date);
+    candidates
+}
+
+/// Recovers the start of every time slot whose name is `name`, most likely
+/// first.
+pub fn decode_timed_candidates(
+    name: &str,
+    salt: &str,
+    key: Option<&Key>,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Vec<NaiveDateTime> {
+    let space = NameSpace::for_name(morphemes, config, name);
+    if moments_are_trinomials(config) && !space.is_trinomial() {
+        return Vec::new();
+    }
+    let rounds = moment_rounds(salt, key, morphemes, config);
+
+    let mut candidates: Vec<NaiveDateTime> = decode_in_space(
+        name,
+        time_domain_size(config),
+        Kind::Timed,
+        &rounds,
+        space,
+        config,
+    )
+    .into_iter()
+    .filter_map(|encoded| decode_date_time(encoded, config))
+    .collect();
+
+    prefer_recent(&mut candidates, |datetime| datetime.date());
+    candidates
+}
+
+/// Returns the preferred candidate from [`decode_candidates`]. Use that
+/// function instead when a name might stand for more than one pair.
+pub fn decode_name(
+    name: &str,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Option<(NaiveDate, u32)> {
+    decode_candidates(name, salt, morphemes, config)
+        .into_iter()
+        .next()
 }
 
 #[cfg(test)]
@@ -271,6 +918,12 @@ mod tests {
     use super::*;
     use crate::data::Category;
 
+    /// The name of the first try, acceptable or not.
+    fn generate_name_internal(encoded: u64, rounds: &Rounds, space: &NameSpace) -> String {
+        let permuted = permute_within(encoded, space.size(), rounds);
+        space.name(&space.indices(permuted))
+    }
+
     #[test]
     fn test_bijectivity() {
         let morphemes = Morphemes::new();
@@ -279,12 +932,12 @@ mod tests {
         for day in 1..=5 {
             for num in 1..=10 {
                 let date = NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
-                let name = generate_name(date, num, "", &morphemes, &config);
-                let decoded = decode_name(&name, "", &morphemes, &config);
+                let name = generate_name(date, num, "", &morphemes, &config).unwrap();
+                let candidates = decode_candidates(&name, "", &morphemes, &config);
 
                 assert_eq!(
-                    decoded,
-                    Some((date, num)),
+                    candidates,
+                    [(date, num)],
                     "Failed for date={}, num={}, name={}",
                     date,
                     num,
@@ -294,6 +947,376 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_out_of_range_tuples_are_refused() {
+        let morphemes = Morphemes::new();
+        let config = Config::default();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let early = NaiveDate::from_ymd_opt(1990, 1, 4).unwrap();
+
+        assert!(generate_name(date, 0, "", &morphemes, &config).is_err());
+        assert!(generate_name(date, 1000, "", &morphemes, &config).is_err());
+        assert!(generate_name(early, 7, "", &morphemes, &config).is_err());
+        assert!(rejected_names(date, 1000, "", None, &morphemes, &config).is_err());
+
+        let moment = early.and_hms_opt(12, 0, 0).unwrap();
+        assert!(generate_timed_name(moment, "", None, &morphemes, &config).is_err());
+    }
+
+    #[test]
+    fn test_encoding_is_collision_free() {
+        // One block of numbers is packed day by day without gaps.
+        let config = Config {
+            number_max: 99,
+            ..Config::default()
+        };
+        let mut date = NaiveDate::from_ymd_opt(config.year_start, 1, 1).unwrap();
+        let last = NaiveDate::from_ymd_opt(config.year_end, 12, 31).unwrap();
+        let mut expected = 0;
+
+        while date <= last {
+            for num in config.number_min..=config.number_max {
+                let encoded = encode_date_number(date, num, &config);
+                assert_eq!(encoded, expected, "Gap or overlap at {} No. {}", date, num);
+                assert_eq!(decode_date_number(encoded, &config), Some((date, num)));
+                expected += 1;
+            }
+            date = date.succ_opt().unwrap();
+        }
+
+        assert_eq!(expected, domain_size(&config));
+        assert_eq!(decode_date_number(expected, &config), None);
+    }
+
+    #[test]
+    fn test_encoding_distinguishes_month_and_day() {
+        let config = Config::default();
+        let a = NaiveDate::from_ymd_opt(2000, 11, 1).unwrap();
+        let b = NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
+
+        assert_ne!(
+            encode_date_number(a, 7, &config),
+            encode_date_number(b, 7, &config)
+        );
+    }
+
+    #[test]
+    fn test_encoding_in_number_blocks() {
+        let config = Config {
+            year_start: 2026,
+            year_end: 2027,
+            epoch_year: 2026,
+            number_max: 250,
+            ..Config::default()
+        };
+        let domain = domain_size(&config);
+        let mut seen = vec![false; domain as usize];
+        let mut date = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+
+        while date.year() <= 2027 {
+            for num in config.number_min..=config.number_max {
+                let encoded = encode_date_number(date, num, &config);
+                assert!(!seen[encoded as usize], "Overlap at {} No. {}", date, num);
+                seen[encoded as usize] = true;
+                assert_eq!(decode_date_number(encoded, &config), Some((date, num)));
+            }
+            date = date.succ_opt().unwrap();
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let narrow = Config {
+            number_max: 99,
+            ..config.clone()
+        };
+        let date = NaiveDate::from_ymd_opt(2027, 3, 8).unwrap();
+        for num in [1, 42, 99] {
+            assert_eq!(
+                encode_date_number(date, num, &config),
+                encode_date_number(date, num, &narrow)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encoding_around_epoch() {
+        let config = Config {
+            year_start: 1900,
+            year_end: 2199,
+            ..Config::default()
+        };
+        let domain = domain_size(&config);
+        let mut seen = vec![false; domain as usize];
+
+        for (year, month, day) in [
+            (1900, 1, 1),
+            (1999, 12, 31),
+            (2000, 1, 1),
+            (2099, 12, 31),
+            (2100, 1, 1),
+            (2199, 12, 31),
+        ] {
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            for number in [config.number_min, config.number_max] {
+                let encoded = encode_date_number(date, number, &config);
+                assert!(encoded < domain);
+                assert!(!seen[encoded as usize]);
+                seen[encoded as usize] = true;
+                assert_eq!(decode_date_number(encoded, &config), Some((date, number)));
+            }
+        }
+
+        let date = NaiveDate::from_ymd_opt(2042, 9, 30).unwrap();
+        assert_eq!(
+            encode_date_number(date, 64, &config),
+            encode_date_number(date, 64, &Config::default())
+        );
+    }
+
+    #[test]
+    fn test_wider_range_keeps_first_choice_names() {
+        let morphemes = Morphemes::new();
+        let narrow = Config {
+            number_max: 99,
+            ..Config::default()
+        };
+        let space = NameSpace::for_config(&morphemes, &narrow);
+        let wide = Config {
+            year_start: 1900,
+            year_end: 2199,
+            layout: Layout::Domain,
+            ..narrow.clone()
+        };
+        let rounds = Rounds::new("", None);
+
+        for (year, month, day) in [(2000, 1, 1), (2026, 1, 4), (2099, 12, 31)] {
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            let first_choice =
+                generate_name_internal(encode_date_number(date, 3, &narrow), &rounds, &space);
+
+            assert_eq!(
+                generate_name(date, 3, "", &morphemes, &wide).unwrap(),
+                first_choice
+            );
+        }
+
+        let date = NaiveDate::from_ymd_opt(1994, 5, 2).unwrap();
+        let name = generate_name(date, 12, "", &morphemes, &wide).unwrap();
+        assert_eq!(
+            decode_candidates(&name, "", &morphemes, &wide),
+            vec![(date, 12)]
+        );
+    }
+
+    #[test]
+    fn test_wider_ranges_keep_retried_names() {
+        let morphemes = Morphemes::new();
+        let narrow = Config::default();
+        let wider = [
+            Config {
+                year_start: 1990,
+                year_end: 2105,
+                ..Config::default()
+            },
+            Config {
+                number_max: 105,
+                ..Config::default()
+            },
+        ];
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let mut retried = 0;
+
+        for number in 1..=99 {
+            let name = generate_name(date, number, "", &morphemes, &narrow).unwrap();
+            if rejected_names(date, number, "", None, &morphemes, &narrow)
+                .unwrap()
+                .is_empty()
+            {
+                continue;
+            }
+
+            for config in &wider {
+                assert_eq!(
+                    generate_name(date, number, "", &morphemes, config).unwrap(),
+                    name
+                );
+            }
+            retried += 1;
+        }
+
+        assert!(retried > 0, "No tuple needed a quality retry");
+    }
+
+    #[test]
+    fn test_permutation_inverts() {
+        let key = Key::from_bytes([7; 16]);
+
+        for rounds in [Rounds::new("patch", None), Rounds::new("patch", Some(&key))] {
+            for half_bits in [10, 13, 16] {
+                for x in (0..1u64 << (2 * half_bits)).step_by(7919) {
+                    let permuted = permute(x, &rounds, half_bits);
+                    assert!(permuted < 1 << (2 * half_bits));
+                    assert_eq!(unpermute(permuted, &rounds, half_bits), x);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_permutation_within_is_exact() {
+        let rounds = Rounds::new("patch", None);
+
+        for size in [1, 1000, 4096, 4097, 70_001] {
+            let mut seen = vec![false; size as usize];
+
+            for x in 0..size {
+                let permuted = permute_within(x, size, &rounds);
+                assert!(permuted < size);
+                assert!(!seen[permuted as usize], "{} reached twice", permuted);
+                seen[permuted as usize] = true;
+                assert_eq!(unpermute_within(permuted, size, &rounds), x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quality_filter_keeps_names_unique() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes);
+        let config = Config {
+            epoch_year: 2026,
+            year_start: 2026,
+            year_end: 2027,
+            number_max: 99,
+            ..Config::default()
+        };
+        let rounds = Rounds::new("", None);
+        let strides = Strides::new(&space, domain_size(&config), &config, Kind::Numbered);
+        assert!(strides.hold(domain_size(&config)));
+        let mut placement = Placement {
+            space,
+            strides,
+            value: 0,
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        let mut retried = 0;
+
+        for encoded in 0..domain_size(&config) {
+            placement.value = encoded;
+            let name = generate_in_space(&placement, &rounds, &config).unwrap();
+            if name != generate_name_internal(encoded, &rounds, &placement.space) {
+                retried += 1;
+            }
+            assert!(seen.insert(name.clone()), "{} issued twice", name);
+        }
+
+        assert!(retried > 0, "No tuple needed a quality retry");
+    }
+
+    #[test]
+    fn test_rejected_names() {
+        let morphemes = Morphemes::new();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let strict = Config::default();
+        let lax = Config {
+            enforce_phonotactics: false,
+            ..strict.clone()
+        };
+
+        assert_eq!(
+            rejected_names(date, 11, "", None, &morphemes, &strict).unwrap(),
+            vec![(
+                "Infralymphen extremum".to_string(),
+                vec![Rejection::BannedCluster]
+            )]
+        );
+        assert_eq!(
+            generate_name(date, 11, "", &morphemes, &lax).unwrap(),
+            "Infralymphen extremum"
+        );
+        assert!(rejected_names(date, 11, "", None, &morphemes, &lax)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_quality_retries_decode() {
+        let morphemes = Morphemes::new();
+        let config = Config {
+            epoch_year: 2026,
+            year_start: 2026,
+            year_end: 2027,
+            number_max: 99,
+            ..Config::default()
+        };
+        let rounds = Rounds::new("", None);
+        let mut checked = 0;
+
+        for encoded in 0..domain_size(&config) {
+            let placement = place_number(encoded, &morphemes, &config).unwrap();
+            let name = generate_in_space(&placement, &rounds, &config).unwrap();
+            if name == generate_name_internal(encoded, &rounds, &placement.space) {
+                continue;
+            }
+
+            let expected = decode_date_number(encoded, &config).unwrap();
+            assert_eq!(
+                decode_candidates(&name, "", &morphemes, &config),
+                vec![expected]
+            );
+            checked += 1;
+            if checked == 100 {
+                break;
+            }
+        }
+
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let morphemes = Morphemes::new();
+        let config = Config::default();
+        assert!(check_capacity(&morphemes, &config).is_ok());
+
+        // Pairs beyond the binomial stride get trinomials.
+        let counted = Config {
+            number_max: 9999,
+            ..Config::default()
+        };
+        assert!(check_capacity(&morphemes, &counted).is_ok());
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let name = generate_name(date, 9999, "", &morphemes, &counted).unwrap();
+        assert_eq!(name.split_whitespace().count(), 3);
+        assert_eq!(
+            decode_candidates(&name, "", &morphemes, &counted),
+            vec![(date, 9999)]
+        );
+
+        let crowded = Config {
+            number_max: 99999,
+            ..Config::default()
+        };
+        assert!(check_capacity(&morphemes, &crowded).is_err());
+        let binomials_only = Config {
+            layout: Layout::Domain,
+            ..counted
+        };
+        assert!(check_capacity(&morphemes, &binomials_only).is_err());
+
+        // A range that leaves no room to retry a rejected name is refused,
+        // even though every pair would get a name of its own.
+        let no_retries = Config {
+            year_start: 2000,
+            year_end: 2059,
+            number_max: 999,
+            layout: Layout::Domain,
+            ..Config::default()
+        };
+        assert!(domain_size(&no_retries) <= NameSpace::new(&morphemes).size());
+        assert!(check_capacity(&morphemes, &no_retries).is_err());
+    }
+
     #[test]
     fn test_pronounceability() {
         let morphemes = Morphemes::new();
@@ -301,8 +1324,9 @@ mod tests {
 
         for num in 1..=20 {
             let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
-            let name = generate_name(date, num, "", &morphemes, &config);
-            let score = pronounceability_score(&name);
+            let name = generate_name(date, num, "", &morphemes, &config).unwrap();
+            let genus = name.split_whitespace().next().unwrap();
+            let score = config.pronounceability.score(genus);
 
             assert!(
                 score >= config.min_pronounceability_score,
@@ -320,8 +1344,8 @@ mod tests {
         let config = Config::default();
         let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
 
-        let name1 = generate_name(date, 1, "", &morphemes, &config);
-        let name2 = generate_name(date, 1, "salt", &morphemes, &config);
+        let name1 = generate_name(date, 1, "", &morphemes, &config).unwrap();
+        let name2 = generate_name(date, 1, "salt", &morphemes, &config).unwrap();
 
         assert_ne!(name1, name2, "Names with different salts should differ");
 
@@ -335,10 +1359,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timed_names() {
+        let morphemes = Morphemes::new();
+        let config = Config::default();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+        let at = |h, m| date.and_hms_opt(h, m, 0).unwrap();
+        let name = generate_timed_name(at(14, 37), "", None, &morphemes, &config).unwrap();
+
+        assert_eq!(
+            name,
+            generate_timed_name(at(14, 36), "", None, &morphemes, &config).unwrap()
+        );
+        assert_ne!(
+            name,
+            generate_timed_name(at(14, 39), "", None, &morphemes, &config).unwrap()
+        );
+        assert_eq!(
+            decode_timed_candidates(&name, "", None, &morphemes, &config),
+            vec![at(14, 36)]
+        );
+
+        for (h, m) in [(0, 0), (23, 59)] {
+            let encoded = encode_date_time(at(h, m), &config);
+            assert!(encoded < time_domain_size(&config));
+            assert_eq!(
+                decode_date_time(encoded, &config),
+                Some(at(h, m - m % config.minutes_per_slot))
+            );
+        }
+    }
+
+    #[test]
+    fn test_timed_and_numbered_names_differ() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes);
+        let numbered = Rounds::new("", None);
+        let timed = numbered.for_times();
+
+        let same = (0..1000)
+            .filter(|&x| {
+                generate_name_internal(x, &numbered, &space)
+                    == generate_name_internal(x, &timed, &space)
+            })
+            .count();
+        assert!(same < 5);
+    }
+
+    #[test]
+    fn test_timed_and_numbered_names_stay_apart() {
+        let morphemes = Morphemes::new();
+        let config = Config {
+            trinomials: true,
+            ..Config::default()
+        };
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+        for number in 1..=99 {
+            let name = generate_name(date, number, "", &morphemes, &config).unwrap();
+            assert_eq!(
+                decode_timed_candidates(&name, "", None, &morphemes, &config),
+                Vec::new()
+            );
+        }
+        for slot in 0..99 {
+            let at = date.and_hms_opt(0, 0, 0).unwrap() + chrono::Duration::minutes(3 * slot);
+            let name = generate_timed_name(at, "", None, &morphemes, &config).unwrap();
+            assert_eq!(
+                decode_candidates(&name, "", &morphemes, &config),
+                Vec::new()
+            );
+        }
+    }
+
+    #[test]
+    fn test_trinomials() {
+        let morphemes = Morphemes::new();
+        let config = Config {
+            number_max: 9999,
+            trinomials: true,
+            ..Config::default()
+        };
+        assert!(check_capacity(&morphemes, &config).is_ok());
+
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        for number in [1, 350, 9999] {
+            let name = generate_name(date, number, "patch", &morphemes, &config).unwrap();
+            assert_eq!(name.split_whitespace().count(), 3);
+            assert_eq!(
+                decode_candidates(&name, "patch", &morphemes, &config),
+                vec![(date, number)]
+            );
+        }
+
+        // Binomials name the first pairs whatever the trinomial setting.
+        let binomial = generate_name(date, 3, "", &morphemes, &Config::default()).unwrap();
+        assert_eq!(
+            decode_candidates(&binomial, "", &morphemes, &config),
+            vec![(date, 3)]
+        );
+    }
+
+    #[test]
+    fn test_private_key() {
+        let morphemes = Morphemes::new();
+        let config = Config::default();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let key = Key::from_bytes([7; 16]);
+        let other = Key::from_bytes([8; 16]);
+
+        let public = generate_name(date, 3, "patch", &morphemes, &config).unwrap();
+        let private =
+            generate_name_keyed(date, 3, "patch", Some(&key), &morphemes, &config).unwrap();
+        assert_ne!(public, private);
+        assert_ne!(
+            private,
+            generate_name_keyed(date, 3, "patch", Some(&other), &morphemes, &config).unwrap()
+        );
+
+        assert_eq!(
+            decode_candidates_keyed(&private, "patch", Some(&key), &morphemes, &config),
+            vec![(date, 3)]
+        );
+        assert!(!decode_candidates(&private, "patch", &morphemes, &config).contains(&(date, 3)));
+        assert!(
+            !decode_candidates_keyed(&private, "patch", Some(&other), &morphemes, &config)
+                .contains(&(date, 3))
+        );
+    }
+
     #[test]
     fn test_consonant_suffix_needs_connector() {
         let prefix = Morpheme {
             text: "Ecto",
+            gloss: "",
             origin: Origin::Greek,
             category: Category::Position,
         };
@@ -347,10 +1502,27 @@ mod tests {
         assert_eq!(result, "Ectoalimentoma");
     }
 
+    #[test]
+    fn test_connector_follows_root_origin() {
+        let prefix = Morpheme {
+            text: "Rubi",
+            gloss: "",
+            origin: Origin::Latin,
+            category: Category::Colour,
+        };
+
+        assert_eq!(assemble_genus(&prefix, "clas", "ma"), "Rubiclasima");
+        assert_eq!(
+            assemble_genus_joined(&prefix, "clas", Origin::Greek, "ma"),
+            "Rubiclasoma"
+        );
+    }
+
     #[test]
     fn test_vowel_suffix_no_connector() {
         let prefix = Morpheme {
             text: "Neo",
+            gloss: "",
             origin: Origin::Greek,
             category: Category::Time,
         };
@@ -363,6 +1535,7 @@ mod tests {
     fn test_both_vowels_elision() {
         let prefix = Morpheme {
             text: "Hydro",
+            gloss: "",
             origin: Origin::Greek,
             category: Category::Environment,
         };
@@ -0,0 +1,171 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Private keys for studio namespaces.
+//!
+//! Without a key the permutation is keyed by a public hash of the type, so
+//! anyone who knows the type can reproduce and decode the names. With a key
+//! every Feistel round is a SipHash-2-4 call under a subkey derived from the
+//! key and the type, and names are unpredictable to anyone without the key.
+
+use std::fmt;
+use std::path::Path;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Key {
+    k0: u64,
+    k1: u64,
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+impl Key {
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        let (k0, k1) = bytes.split_at(8);
+        Self {
+            k0: u64::from_le_bytes(k0.try_into().unwrap()),
+            k1: u64::from_le_bytes(k1.try_into().unwrap()),
+        }
+    }
+
+    /// Parses 32 hexadecimal digits, as written by `openssl rand -hex 16`.
+    pub fn from_hex(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        if text.len() != 32 || !text.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err("Key must be 32 hexadecimal digits (128 bits)".to_string());
+        }
+
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&text[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        Ok(Self::from_bytes(bytes))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read key file {}: {}", path.display(), e))?;
+        Self::from_hex(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Derives an independent key for `context`.
+    pub fn derive(&self, context: &[u8]) -> Self {
+        let mut input = Vec::with_capacity(context.len() + 1);
+        input.push(0);
+        input.extend_from_slice(context);
+        let k0 = self.hash(&input);
+        input[0] = 1;
+        let k1 = self.hash(&input);
+
+        Self { k0, k1 }
+    }
+
+    /// SipHash-2-4 of `data`.
+    pub fn hash(&self, data: &[u8]) -> u64 {
+        let mut v = [
+            self.k0 ^ 0x736f6d6570736575,
+            self.k1 ^ 0x646f72616e646f6d,
+            self.k0 ^ 0x6c7967656e657261,
+            self.k1 ^ 0x7465646279746573,
+        ];
+
+        let mut chunks = data.chunks_exact(8);
+        for chunk in &mut chunks {
+            let m = u64::from_le_bytes(chunk.try_into().unwrap());
+            v[3] ^= m;
+            sip_round(&mut v);
+            sip_round(&mut v);
+            v[0] ^= m;
+        }
+
+        let mut last = [0u8; 8];
+        last[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+        last[7] = data.len() as u8;
+        let m = u64::from_le_bytes(last);
+        v[3] ^= m;
+        sip_round(&mut v);
+        sip_round(&mut v);
+        v[0] ^= m;
+
+        v[2] ^= 0xff;
+        for _ in 0..4 {
+            sip_round(&mut v);
+        }
+
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+}
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_key() -> Key {
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        Key::from_bytes(bytes)
+    }
+
+    #[test]
+    fn test_siphash_reference_vectors() {
+        let key = reference_key();
+        let message: Vec<u8> = (0..15).collect();
+
+        assert_eq!(key.hash(&[]), 0x726fdb47dd0e0e31);
+        assert_eq!(key.hash(&message[..1]), 0x74f839c593dc67fd);
+        assert_eq!(key.hash(&message[..8]), 0x93f5f5799a932462);
+        assert_eq!(key.hash(&message), 0xa129ca6149be45e5);
+    }
+
+    #[test]
+    fn test_from_hex() {
+        assert_eq!(
+            Key::from_hex("000102030405060708090a0b0c0d0e0f\n").unwrap(),
+            reference_key()
+        );
+        assert!(Key::from_hex("0001").is_err());
+        assert!(Key::from_hex("zz0102030405060708090a0b0c0d0e0f").is_err());
+    }
+
+    #[test]
+    fn test_derive_separates_contexts() {
+        let key = reference_key();
+
+        assert_ne!(key.derive(b"patch"), key.derive(b"drums"));
+        assert_ne!(key.derive(b""), key);
+        assert_eq!(format!("{:?}", key), "Key(..)");
+    }
+}
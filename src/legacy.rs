@@ -0,0 +1,269 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Generation and decoding with the date packing used by holotype 0.1.
+//!
+//! That packing lets the month field spill into the year field, so some
+//! names stand for more than one (date, number) pair. It is kept only so that
+//! names issued before the fix can still be reproduced and looked up; new
+//! names should always come from [`crate::generator`].
+
+use crate::config::Config;
+use crate::data::Morphemes;
+use crate::generator::{
+    assemble_genus, feistel_round, get_safe_suffixes, hash_salt, prefer_recent,
+    MAX_QUALITY_ATTEMPTS,
+};
+use crate::quality;
+use chrono::{Datelike, NaiveDate};
+
+const LEGACY_YEAR_START: i32 = 2000;
+const LEGACY_YEAR_END: i32 = 2099;
+
+fn encode_date_number(date: NaiveDate, number: u32) -> u64 {
+    let year = (date.year() - LEGACY_YEAR_START) as u64;
+    let month = date.month() as u64;
+    let day = date.day() as u64;
+    let num = number as u64;
+
+    year * 1_000_000 + month * 100_000 + day * 1_000 + num
+}
+
+fn permute(x: u64, salt_hash: u64) -> u64 {
+    const ROUNDS: usize = 4;
+    const MASK: u64 = 0xFFFFFFFF;
+
+    let mut left = (x >> 32) & MASK;
+    let mut right = x & MASK;
+
+    for round in 0..ROUNDS {
+        let round_key = salt_hash.wrapping_mul(round as u64 + 1);
+        let f_output = feistel_round(right, round_key);
+        (left, right) = (right, left ^ f_output);
+    }
+
+    (left << 32) | right
+}
+
+/// Returns the name along with the root and suffix of its genus.
+fn generate_name_internal<'a>(
+    encoded: u64,
+    salt_hash: u64,
+    morphemes: &Morphemes<'a>,
+) -> (String, &'a str, &'a str) {
+    let permuted = permute(encoded, salt_hash);
+
+    let genus_seed = permuted & 0xFFFFFFFF;
+    let species_seed = (permuted >> 32) & 0xFFFFFFFF;
+
+    let safe_suffixes = get_safe_suffixes(morphemes);
+
+    let prefix_idx = (genus_seed % morphemes.prefixes.len() as u64) as usize;
+    let root_idx = ((genus_seed >> 8) % morphemes.roots.len() as u64) as usize;
+    let suffix_idx = ((genus_seed >> 16) % safe_suffixes.len() as u64) as usize;
+
+    let prefix = &morphemes.prefixes[prefix_idx];
+    let root = morphemes.roots[root_idx].text;
+    let genus_suffix = safe_suffixes[suffix_idx].text;
+
+    let genus = assemble_genus(prefix, root, genus_suffix);
+
+    let category = prefix.category;
+
+    let suitable_descriptors: Vec<&str> = morphemes
+        .species_descriptors
+        .iter()
+        .filter(|d| d.category.is_none() || d.category == Some(category))
+        .map(|d| d.text)
+        .collect();
+
+    let descriptor_idx = (species_seed % suitable_descriptors.len() as u64) as usize;
+    let species = suitable_descriptors[descriptor_idx];
+
+    (format!("{} {}", genus, species), root, genus_suffix)
+}
+
+pub fn generate_name(
+    date: NaiveDate,
+    number: u32,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> String {
+    let base_encoded = encode_date_number(date, number);
+    let salt_hash = hash_salt(salt);
+
+    for quality_offset in 0..MAX_QUALITY_ATTEMPTS {
+        let encoded = if quality_offset == 0 {
+            base_encoded
+        } else {
+            base_encoded
+                .wrapping_mul(0x9e3779b97f4a7c15)
+                .wrapping_add(quality_offset as u64)
+        };
+
+        let (name, root, suffix) = generate_name_internal(encoded, salt_hash, morphemes);
+        let genus = name.split_whitespace().next().unwrap_or("");
+
+        if quality::is_acceptable(genus, root, suffix, config) {
+            return name;
+        }
+    }
+
+    generate_name_internal(base_encoded, salt_hash, morphemes).0
+}
+
+/// Searches the whole range for the pairs that generate `name`, most likely
+/// first. The packing overlaps, so there can be several.
+pub fn decode_candidates(
+    name: &str,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Vec<(NaiveDate, u32)> {
+    let mut candidates = Vec::new();
+
+    for year in LEGACY_YEAR_START..=LEGACY_YEAR_END {
+        for month in 1..=12 {
+            for day in 1..=days_in_month(year, month) {
+                if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+                    for num in config.number_min..=config.number_max {
+                        if generate_name(date, num, salt, morphemes, config) == name {
+                            candidates.push((date, num));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    prefer_recent(&mut candidates, |&(date, _)| date);
+    candidates
+}
+
+/// Searches the month either side of today for the pairs that generate
+/// `name`, nearest first, which is where a name is usually looked up and
+/// takes a fraction of the time [`decode_candidates`] does.
+pub fn decode_recent(
+    name: &str,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+) -> Vec<(NaiveDate, u32)> {
+    let today = chrono::Local::now().date_naive();
+    let mut candidates = Vec::new();
+
+    for offset in -30..=30 {
+        let Some(date) = today.checked_add_signed(chrono::Duration::days(offset)) else {
+            continue;
+        };
+        if !(LEGACY_YEAR_START..=LEGACY_YEAR_END).contains(&date.year()) {
+            continue;
+        }
+        for num in config.number_min..=config.number_max {
+            if generate_name(date, num, salt, morphemes, config) == name {
+                candidates.push((date, num));
+            }
+        }
+    }
+
+    prefer_recent(&mut candidates, |&(date, _)| date);
+    candidates
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 => {
+            if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+                29
+            } else {
+                28
+            }
+        }
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_packing_overlaps() {
+        let a = NaiveDate::from_ymd_opt(2000, 11, 1).unwrap();
+        let b = NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
+
+        assert_eq!(encode_date_number(a, 7), encode_date_number(b, 7));
+    }
+
+    #[test]
+    fn test_legacy_decode_reports_every_candidate() {
+        let scheme = crate::scheme::find("v1").unwrap();
+        let morphemes = scheme.morphemes();
+        let config = Config {
+            number_min: 5,
+            number_max: 6,
+            ..scheme.config.clone()
+        };
+        let issued = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let overlapping = NaiveDate::from_ymd_opt(2025, 11, 4).unwrap();
+
+        let name = generate_name(issued, 5, "", &morphemes, &config);
+        let mut candidates = decode_candidates(&name, "", &morphemes, &config);
+        candidates.sort();
+
+        assert_eq!(candidates, [(overlapping, 5), (issued, 5)]);
+    }
+
+    #[test]
+    fn test_legacy_decode_recent() {
+        let scheme = crate::scheme::find("v1").unwrap();
+        let morphemes = scheme.morphemes();
+        let config = &scheme.config;
+        let today = chrono::Local::now().date_naive();
+        let week_ago = today - chrono::Duration::days(7);
+
+        let name = generate_name(week_ago, 12, "", &morphemes, config);
+        assert_eq!(
+            decode_recent(&name, "", &morphemes, config)[0],
+            (week_ago, 12)
+        );
+    }
+
+    #[test]
+    fn test_legacy_names_are_unchanged() {
+        let scheme = crate::scheme::find("v1").unwrap();
+        let morphemes = scheme.morphemes();
+        let config = scheme.config.clone();
+
+        let cases = [
+            ((2000, 1, 15), 1, "patch", "Monojejune sylvaticus"),
+            ((2000, 10, 1), 7, "", "Anisoorche riparius"),
+            ((2026, 1, 4), 42, "test_salt", "Vermiplasmos hybridus"),
+            ((2099, 12, 31), 99, "", "Stenocyten ambiguus"),
+        ];
+
+        for ((year, month, day), number, salt, expected) in cases {
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            assert_eq!(
+                generate_name(date, number, salt, &morphemes, &config),
+                expected
+            );
+        }
+    }
+}
@@ -15,74 +15,559 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+pub mod blocklist;
 pub mod cli;
 pub mod config;
 pub mod data;
 pub mod decoder;
+pub mod explain;
 pub mod formatter;
 pub mod generator;
+pub mod key;
+pub mod legacy;
+pub mod lint;
+pub mod ngram;
 pub mod phonotactics;
 pub mod pronounceability;
+pub mod quality;
+pub mod scheme;
+pub mod similarity;
+pub mod space;
+pub mod verify;
 
-use chrono::{Datelike, Local, NaiveDate};
-use cli::Cli;
-use config::Config;
-use data::Morphemes;
+use blocklist::Blocklist;
+use chrono::{Local, NaiveDate, NaiveTime};
+use cli::{BatchArgs, Cli, Command, MomentArgs, NameArgs};
+use config::Overrides;
+use data::Pack;
 use formatter::DecodedName;
+use key::Key;
+use ngram::NgramModel;
+use similarity::Ledger;
+use std::path::Path;
+use std::sync::Arc;
 
 pub fn run(cli: Cli) -> Result<(), String> {
-    let morphemes = Morphemes::new();
-    let config = Config::default();
-    config.validate()?;
+    match cli.command {
+        Some(Command::Generate(args)) => {
+            run_generate(args.number, &args.moment, args.why_rejected, &args.names)
+        }
+        Some(Command::Decode(args)) => run_decode(&args.name, &args.names),
+        Some(Command::Batch(args)) => run_batch(&args),
+        Some(Command::Explain(args)) => run_explain(&args.name, &args.names),
+        Some(Command::Stats(args)) => run_stats(&args.names),
+        Some(Command::Verify) => run_verify(),
+        Some(Command::LintData {
+            morphemes,
+            theme,
+            scheme,
+        }) => run_lint_data(morphemes.as_deref(), theme.as_deref(), scheme.as_deref()),
+        Some(Command::TrainModel {
+            words,
+            order,
+            output,
+        }) => run_train_model(&words, order, output.as_deref()),
+        None if cli.extract => {
+            let name = cli.value.ok_or("Name required for extraction")?;
+            run_decode(&name, &cli.names)
+        }
+        None => {
+            let number = match (cli.index, cli.value) {
+                (Some(index), _) => Some(index),
+                (None, Some(value)) => Some(
+                    value
+                        .parse::<u32>()
+                        .map_err(|_| format!("Invalid number: {}", value))?,
+                ),
+                (None, None) => None,
+            };
+            run_generate(number, &cli.moment, cli.why_rejected, &cli.names)
+        }
+    }
+}
 
-    let salt = cli.salt.as_deref().unwrap_or("");
+/// What [`NameArgs`] choose, with their files loaded.
+struct Setup {
+    /// The scheme asked for, if any, with the overrides applied.
+    scheme: Option<scheme::Scheme>,
+    overrides: Overrides,
+    salt: String,
+    key: Option<Key>,
+    ledger: Option<Ledger>,
+}
 
-    if cli.extract {
-        let name = cli.value.ok_or("Name required for extraction")?;
+impl Setup {
+    fn load(args: &NameArgs) -> Result<Self, String> {
+        let scheme = args.scheme.as_deref().map(find_scheme).transpose()?;
+        let salt = args.salt.clone().unwrap_or_default();
+        let theme = match args.theme.as_deref() {
+            Some(text) => config::parse_themes(text, &salt)?,
+            None => None,
+        };
 
-        match decoder::decode(&name, salt, &morphemes, &config) {
-            Some((date, number)) => {
-                let decoded = DecodedName::new(name, date, number, salt);
-                decoded.display();
-                Ok(())
-            }
-            None => Err(format!("Could not decode name: {}", name)),
-        }
-    } else {
-        let number = if let Some(idx) = cli.index {
-            idx
-        } else if let Some(val) = cli.value {
-            val.parse::<u32>()
-                .map_err(|_| format!("Invalid number: {}", val))?
+        let key = args.key.as_deref().map(Key::from_file).transpose()?;
+        // Configs and schemes are plain values, so they share the model,
+        // blocklist and pack they refer to.
+        let model = args
+            .model
+            .as_deref()
+            .map(NgramModel::from_file)
+            .transpose()?
+            .map(Arc::new);
+        let blocklist = args
+            .blocklist
+            .as_deref()
+            .map(Blocklist::from_file)
+            .transpose()?
+            .map(Arc::new);
+        let ledger = args
+            .distinct
+            .as_deref()
+            .map(Ledger::from_file)
+            .transpose()?;
+        let pack = args
+            .morphemes
+            .as_deref()
+            .map(Pack::from_file)
+            .transpose()?
+            .map(Arc::new);
+        let overrides = Overrides {
+            trinomials: args.trinomial,
+            pure_origins: args.pure,
+            categories: match args.categories.as_deref() {
+                Some(text) => config::parse_categories_for(text, &salt)?,
+                None => None,
+            },
+            model,
+            blocklist,
+            pack,
+            theme,
+            ..Overrides::parse(args.years.as_deref(), args.epoch, args.numbers.as_deref())?
+        };
+        let scheme = scheme
+            .map(|scheme| scheme.with_overrides(&overrides))
+            .transpose()?;
+
+        Ok(Self {
+            scheme,
+            overrides,
+            salt,
+            key,
+            ledger,
+        })
+    }
+
+    /// The scheme to generate with: the one asked for, or the newest, with
+    /// its config checked against the ranges.
+    fn generating_scheme(&self) -> Result<scheme::Scheme, String> {
+        let scheme = match &self.scheme {
+            Some(scheme) => scheme.clone(),
+            None => scheme::latest().with_overrides(&self.overrides)?,
+        };
+        scheme.config.validate()?;
+        generator::check_capacity(&scheme.morphemes(), &scheme.config)?;
+        Ok(scheme)
+    }
+}
+
+fn run_generate(
+    number: Option<u32>,
+    moment: &MomentArgs,
+    why_rejected: bool,
+    args: &NameArgs,
+) -> Result<(), String> {
+    let setup = Setup::load(args)?;
+    let scheme = setup.generating_scheme()?;
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    let (salt, key) = (setup.salt.as_str(), setup.key.as_ref());
+
+    if moment.now || moment.time.is_some() {
+        let datetime = if moment.now {
+            Local::now().naive_local()
         } else {
-            return Err("Number required (provide as argument or use -i)".to_string());
+            let date = parse_date(moment.date.as_deref(), config)?;
+            let time_str = moment.time.as_deref().unwrap_or_default();
+            let time = NaiveTime::parse_from_str(time_str, "%H:%M")
+                .map_err(|_| format!("Invalid time format: {}", time_str))?;
+            date.and_time(time)
+        };
+        generator::check_date(datetime.date(), config)?;
+        generator::check_time_capacity(&morphemes, config)?;
+
+        if why_rejected {
+            let rejected = scheme.rejected_timed_names(datetime, salt, key, &morphemes, config)?;
+            print_rejected(&rejected);
+        }
+        let line = match (args.distinct.as_deref(), &setup.ledger) {
+            (Some(path), Some(ledger)) => {
+                // Later slots of the same day, for a moment whose name is too
+                // close to an issued one.
+                let slot = chrono::Duration::minutes(config.minutes_per_slot as i64);
+                let slots = (0..)
+                    .map(|i| datetime + slot * i)
+                    .take_while(|later| later.date() == datetime.date())
+                    .map(|later| {
+                        let name = scheme.generate_timed_name(later, salt, key, &morphemes, config);
+                        (later.format("%H:%M").to_string(), name)
+                    });
+                let (slot, name) = issue_distinct(path, ledger, slots)?;
+                format!("{}\t{}", slot, name)
+            }
+            _ => scheme.generate_timed_name(datetime, salt, key, &morphemes, config)?,
         };
+        println!("{}", line);
+        return Ok(());
+    }
+
+    let number = number.ok_or("Number required (or use --time or --now)")?;
+    generator::check_number(number, config)?;
+    let date = parse_date(moment.date.as_deref(), config)?;
+
+    if why_rejected {
+        let rejected = scheme.rejected_names(date, number, salt, key, &morphemes, config)?;
+        print_rejected(&rejected);
+    }
+    let line = match (args.distinct.as_deref(), &setup.ledger) {
+        (Some(path), Some(ledger)) => {
+            let numbers = (number..=config.number_max).map(|later| {
+                let name = scheme.generate_name(date, later, salt, key, &morphemes, config);
+                (later.to_string(), name)
+            });
+            let (number, name) = issue_distinct(path, ledger, numbers)?;
+            format!("{}\t{}", number, name)
+        }
+        _ => scheme.generate_name(date, number, salt, key, &morphemes, config)?,
+    };
+    println!("{}", line);
+    Ok(())
+}
+
+fn run_decode(name: &str, args: &NameArgs) -> Result<(), String> {
+    let setup = Setup::load(args)?;
+    let (salt, key) = (setup.salt.as_str(), setup.key.as_ref());
+
+    let candidates = match &setup.scheme {
+        Some(scheme) => decoder::decode(name, salt, key, scheme)?,
+        None => {
+            let mut candidates = decoder::decode_any(name, salt, key, &setup.overrides);
+            // Chance matches at most: the name may be from Holotype 0.1, and
+            // a match there is more likely than a chance one.
+            if decoder::needs_legacy(&candidates, key) {
+                eprintln!(
+                    "\x1b[2mNo numbered work matches in the current schemes; \
+                     searching v1\x1b[0m"
+                );
+                let legacy = decoder::decode_legacy(name, salt, &setup.overrides);
+                if !legacy.is_empty() {
+                    candidates = legacy;
+                }
+            }
+            candidates
+        }
+    };
+
+    DecodedName::from_candidates(name.to_string(), candidates, salt)
+        .ok_or_else(|| format!("Could not decode name: {}", name))?
+        .display();
+    Ok(())
+}
+
+/// Prints one line per date and number: the date, the number and its name,
+/// separated by tabs.
+fn run_batch(args: &BatchArgs) -> Result<(), String> {
+    if args.names.distinct.is_some() {
+        return Err(
+            "batch cannot keep names distinct; generate them one at a time with --distinct"
+                .to_string(),
+        );
+    }
+
+    let setup = Setup::load(&args.names)?;
+    let scheme = setup.generating_scheme()?;
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    let (salt, key) = (setup.salt.as_str(), setup.key.as_ref());
+
+    let (first, last) = match args.range.split_once('-') {
+        Some(_) => config::parse_range(&args.range, "number")?,
+        None => {
+            let number = args
+                .range
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid number: {}", args.range))?;
+            (number, number)
+        }
+    };
+    if first > last {
+        return Err(format!(
+            "Invalid number range: {} (the first number is greater than the last)",
+            args.range
+        ));
+    }
+    generator::check_number(first, config)?;
+    generator::check_number(last, config)?;
+
+    let start = parse_date(args.date.as_deref(), config)?;
+    for date in start.iter_days().take(args.days as usize) {
+        generator::check_date(date, config)?;
+        for number in first..=last {
+            let name = scheme.generate_name(date, number, salt, key, &morphemes, config)?;
+            println!("{}\t{}\t{}", date, number, name);
+        }
+    }
+    Ok(())
+}
 
-        if !(config.number_min..=config.number_max).contains(&number) {
-            return Err(format!(
-                "Number {} is out of range [{}, {}]",
-                number, config.number_min, config.number_max
-            ));
+fn run_stats(args: &NameArgs) -> Result<(), String> {
+    let setup = Setup::load(args)?;
+    let scheme = match setup.scheme {
+        Some(scheme) => scheme,
+        None => scheme::latest().with_overrides(&setup.overrides)?,
+    };
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    config.validate()?;
+
+    let space = space::NameSpace::for_config(&morphemes, config);
+    let names = space.size();
+
+    match scheme.theme {
+        Some(theme) => println!("Scheme: {} ({})", scheme.id, theme.id),
+        None => println!("Scheme: {}", scheme.id),
+    }
+    println!(
+        "Tables: {} prefixes, {} roots, {} genus suffixes, {} species and {} subspecies \
+         descriptors",
+        morphemes.prefixes.len(),
+        morphemes.roots.len(),
+        morphemes.genus_suffixes.len(),
+        morphemes.species_descriptors.len(),
+        morphemes.subspecies_descriptors.len()
+    );
+    println!(
+        "Names: {} {}",
+        names,
+        if space.is_trinomial() {
+            "trinomials"
+        } else {
+            "binomials"
         }
+    );
 
-        let date = if let Some(date_str) = cli.date {
-            NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
-                .map_err(|_| format!("Invalid date format: {}", date_str))?
+    // The room is what the quality retries leave for one kind of tuple.
+    let numbered = generator::domain_size(config);
+    let timed = generator::time_domain_size(config);
+    let room = |count: u64, room: u64| 100.0 * count as f64 / room as f64;
+    println!(
+        "Numbered works: {} ({}-{}, {}-{}), {:.1}% of the room",
+        numbered,
+        config.number_min,
+        config.number_max,
+        config.year_start,
+        config.year_end,
+        room(numbered, generator::number_capacity(&morphemes, config))
+    );
+    let moments = generator::moment_space(&morphemes, config);
+    println!(
+        "Moments: {} ({}-minute slots, {}), {:.1}% of the room",
+        timed,
+        config.minutes_per_slot,
+        if moments.is_trinomial() {
+            "trinomials"
         } else {
-            Local::now().date_naive()
-        };
+            "binomials"
+        },
+        room(timed, generator::time_capacity(&morphemes, config))
+    );
+    // Over 100% of the room, or short of descriptors for trinomials.
+    if let Err(e) = generator::check_capacity(&morphemes, config) {
+        eprintln!(
+            "\x1b[33mWarning: numbered works cannot be named: {}\x1b[0m",
+            e
+        );
+    }
+    if let Err(e) = generator::check_time_capacity(&morphemes, config) {
+        eprintln!("\x1b[33mWarning: moments cannot be named: {}\x1b[0m", e);
+    }
+
+    const SAMPLES: u64 = 10_000;
+    let samples = SAMPLES.min(names);
+    let acceptable = (0..samples)
+        .filter(|&i| space.is_acceptable(&space.indices(i * names / samples), config))
+        .count();
+    println!(
+        "Acceptable: {:.1}% of {} names sampled",
+        100.0 * acceptable as f64 / samples as f64,
+        samples
+    );
+    Ok(())
+}
+
+fn find_scheme(id: &str) -> Result<&'static scheme::Scheme, String> {
+    scheme::find(id).ok_or_else(|| {
+        format!(
+            "Unknown scheme: {} (known: {})",
+            id,
+            scheme::ids().join(", ")
+        )
+    })
+}
 
-        if date.year() < config.year_start || date.year() > config.year_end {
-            return Err(format!(
-                "Date year {} is out of range [{}, {}]",
-                date.year(),
-                config.year_start,
-                config.year_end
-            ));
+/// Issues the first of `names`, each labelled with the number or time it
+/// stands for, that cannot be mistaken for a name in the `--distinct`
+/// ledger, adds it there and returns it with its label, which may be a
+/// later one than asked for. Skipped names are noted in the ledger and on
+/// stderr; their numbers or times are simply left unnamed, so decoding
+/// never needs the ledger.
+fn issue_distinct(
+    path: &Path,
+    ledger: &Ledger,
+    names: impl Iterator<Item = (String, Result<String, String>)>,
+) -> Result<(String, String), String> {
+    for (label, name) in names {
+        let name = name?;
+        match ledger.find_similar(&name) {
+            Some(issued) => {
+                eprintln!(
+                    "\x1b[2mSkipped {} {}: too close to {}\x1b[0m",
+                    label, name, issued
+                );
+                ledger.record_skip(path, &name, issued)?;
+            }
+            None => {
+                ledger.append(path, &name)?;
+                return Ok((label, name));
+            }
         }
+    }
+    Err("Every name left in range is too close to one in the ledger".to_string())
+}
+
+/// Lists rejected names on stderr, keeping stdout to the name itself.
+fn print_rejected(rejected: &[(String, Vec<quality::Rejection>)]) {
+    for (name, rejections) in rejected {
+        let reasons: Vec<String> = rejections.iter().map(|r| r.to_string()).collect();
+        eprintln!("\x1b[2mRejected {}: {}\x1b[0m", name, reasons.join("; "));
+    }
+}
+
+/// Parses `--date`, defaulting to today, and checks it against the range.
+fn parse_date(date_str: Option<&str>, config: &config::Config) -> Result<NaiveDate, String> {
+    let date = if let Some(date_str) = date_str {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date format: {}", date_str))?
+    } else {
+        Local::now().date_naive()
+    };
+
+    generator::check_date(date, config)?;
+    Ok(date)
+}
 
-        let name = generator::generate_name(date, number, salt, &morphemes, &config);
-        println!("{}", name);
-        Ok(())
+fn run_train_model(path: &Path, order: usize, output: Option<&Path>) -> Result<(), String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read word list {}: {}", path.display(), e))?;
+    let words = ngram::read_words(&text);
+    if words.is_empty() {
+        return Err(format!("No words in {}", path.display()));
     }
+
+    let model = NgramModel::train(&words, order)?;
+    match output {
+        Some(path) => std::fs::write(path, model.to_text())
+            .map_err(|e| format!("Cannot write model {}: {}", path.display(), e))?,
+        None => print!("{}", model.to_text()),
+    }
+    Ok(())
+}
+
+fn run_explain(name: &str, args: &NameArgs) -> Result<(), String> {
+    let setup = Setup::load(args)?;
+
+    let etymologies = match &setup.scheme {
+        Some(scheme) => explain::explain(name, &scheme.morphemes(), &scheme.config),
+        None => explain::explain_any(name, &setup.overrides),
+    };
+    if etymologies.is_empty() {
+        return Err(format!("Could not explain name: {}", name));
+    }
+
+    println!("\x1b[1;4m{}\x1b[0m", name);
+    for etymology in etymologies {
+        println!("{}", etymology);
+    }
+    Ok(())
+}
+
+fn run_lint_data(
+    morphemes: Option<&Path>,
+    theme: Option<&str>,
+    scheme: Option<&str>,
+) -> Result<(), String> {
+    let scheme = scheme.map(find_scheme).transpose()?;
+    let pack = morphemes
+        .map(Pack::from_file_unchecked)
+        .transpose()?
+        .map(Arc::new);
+    let overrides = Overrides {
+        pack,
+        theme: theme.map(config::find_theme).transpose()?,
+        ..Overrides::default()
+    };
+    let scheme = scheme
+        .unwrap_or_else(scheme::latest)
+        .with_overrides(&overrides)?;
+
+    // The built-in tables are frozen, so their known findings are only
+    // reported; a pack's are its author's to fix.
+    let (known, findings): (Vec<_>, Vec<_>) = lint::lint(&scheme.morphemes(), &scheme.config)
+        .into_iter()
+        .partition(|finding| overrides.pack.is_none() && finding.is_known());
+    for finding in &known {
+        println!("\x1b[2m{} (known)\x1b[0m", finding);
+    }
+    for finding in &findings {
+        println!("{}", finding);
+    }
+
+    if !findings.is_empty() {
+        return Err(format!(
+            "{} problems in the morpheme tables",
+            findings.len()
+        ));
+    }
+    match known.len() {
+        0 => println!("No problems in the morpheme tables"),
+        n => println!(
+            "No problems in the morpheme tables besides {} known in the frozen built-ins",
+            n
+        ),
+    }
+    Ok(())
+}
+
+fn run_verify() -> Result<(), String> {
+    let vectors = verify::parse_vectors(verify::GOLDEN_VECTORS)?;
+    let report = verify::verify(&vectors);
+
+    for failure in &report.failures {
+        println!("\x1b[31m{}\x1b[0m", failure);
+    }
+
+    if !report.is_ok() {
+        return Err(format!(
+            "{} of {} golden vectors failed",
+            report.failures.len(),
+            vectors.len()
+        ));
+    }
+
+    println!(
+        "{} golden vectors verified ({} generated, {} decoded)",
+        vectors.len(),
+        report.generated,
+        report.decoded
+    );
+    Ok(())
 }
@@ -0,0 +1,415 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks on morpheme tables, built-in or from a pack, for entries that are
+//! malformed or that no name can use.
+
+use crate::config::Config;
+use crate::data::{Morphemes, SpeciesDescriptor};
+use crate::quality;
+use crate::space::{NameIndices, NameSpace};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Endings of English adjectives, which a descriptor latinised by adding
+/// -us to one still has before its Latin ending.
+const ENGLISH_ENDINGS: &[&str] = &["ous", "ful", "less", "ness", "ish", "ive", "ing"];
+
+/// Findings in the built-in tables of some scheme or theme. The tables are
+/// frozen, so these cannot be fixed; `lint-data` reports them as known and
+/// fails only on others.
+const KNOWN_FINDINGS: &[(&str, &str, Problem)] = &[
+    ("prefix", "Xantho", Problem::NeverAcceptable),
+    ("prefix", "Amphi", Problem::NeverAcceptable),
+    ("prefix", "Acantho", Problem::NeverAcceptable),
+    ("root", "laryng", Problem::Duplicate),
+    ("root", "cyst", Problem::Duplicate),
+    ("root", "gon", Problem::Duplicate),
+    ("root", "blast", Problem::Duplicate),
+    ("root", "phag", Problem::Duplicate),
+    ("root", "ton", Problem::Duplicate),
+    ("root", "carp", Problem::Duplicate),
+    ("root", "spor", Problem::Duplicate),
+    ("root", "thec", Problem::Duplicate),
+    ("root", "pil", Problem::Duplicate),
+    ("root", "pter", Problem::Duplicate),
+    ("root", "ophthalm", Problem::NeverAcceptable),
+    ("root", "lymph", Problem::NeverAcceptable),
+    ("root", "anth", Problem::NeverAcceptable),
+    ("species descriptor", "medius", Problem::Duplicate),
+    (
+        "species descriptor",
+        "copiousus",
+        Problem::EnglishEnding { ending: "ous" },
+    ),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    Empty,
+    Duplicate,
+    Hyphenated,
+    NotALetter(char),
+    Capitalisation { capitalised: bool },
+    WrongEnding { ending: &'static str },
+    EnglishEnding { ending: &'static str },
+    NeverAcceptable,
+}
+
+impl Problem {
+    /// Whether the entry cannot be used as written, as opposed to merely
+    /// suspect; packs with such entries are refused.
+    pub fn is_malformed(&self) -> bool {
+        !matches!(
+            self,
+            Problem::EnglishEnding { .. } | Problem::NeverAcceptable
+        )
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::Empty => write!(f, "empty"),
+            Problem::Duplicate => write!(f, "listed more than once"),
+            Problem::Hyphenated => write!(f, "written with a hyphen"),
+            Problem::NotALetter(c) => write!(f, "contains {:?}, not an ASCII letter", c),
+            Problem::Capitalisation { capitalised: true } => {
+                write!(f, "expected a capital and lowercase letters")
+            }
+            Problem::Capitalisation { capitalised: false } => {
+                write!(f, "expected lowercase letters")
+            }
+            Problem::WrongEnding { ending } => {
+                write!(f, "declines as if ending in -{} but does not", ending)
+            }
+            Problem::EnglishEnding { ending } => {
+                write!(f, "keeps the English ending -{}", ending)
+            }
+            Problem::NeverAcceptable => write!(f, "in no genus that passes the quality filter"),
+        }
+    }
+}
+
+/// A problem with an entry of one of the tables.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finding {
+    pub table: &'static str,
+    pub text: String,
+    pub problem: Problem,
+}
+
+impl Finding {
+    /// Whether the finding is one of the known ones in the built-in tables.
+    pub fn is_known(&self) -> bool {
+        KNOWN_FINDINGS.iter().any(|(table, text, problem)| {
+            *table == self.table && *text == self.text && *problem == self.problem
+        })
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:?}: {}", self.table, self.text, self.problem)
+    }
+}
+
+/// Every problem with the tables: [`lint_tables`] and [`lint_genera`].
+pub fn lint(morphemes: &Morphemes, config: &Config) -> Vec<Finding> {
+    let mut findings = lint_tables(morphemes);
+    findings.extend(lint_genera(morphemes, config));
+    findings
+}
+
+/// Entries that are empty, listed twice, not spelled in plain letters with
+/// the case their table needs, and descriptors without the ending their
+/// declension needs or with an English one.
+pub fn lint_tables(morphemes: &Morphemes) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let prefixes = morphemes.prefixes.iter().map(|prefix| prefix.text);
+    check_words(&mut findings, "prefix", prefixes, true);
+    check_words(
+        &mut findings,
+        "root",
+        morphemes.roots.iter().map(|root| root.text),
+        false,
+    );
+    let suffixes = morphemes.genus_suffixes.iter().map(|suffix| suffix.text);
+    check_words(&mut findings, "genus suffix", suffixes, false);
+
+    for (table, descriptors) in [
+        ("species descriptor", &morphemes.species_descriptors),
+        ("subspecies descriptor", &morphemes.subspecies_descriptors),
+    ] {
+        check_words(
+            &mut findings,
+            table,
+            descriptors.iter().map(|d| d.text),
+            false,
+        );
+        for descriptor in descriptors {
+            if let Some(problem) = ending_problem(descriptor) {
+                findings.push(Finding {
+                    table,
+                    text: descriptor.text.to_string(),
+                    problem,
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+/// Prefixes, roots and suffixes, and roots paired with a suffix, that are in
+/// no genus passing the quality filter of `config`. The space still counts
+/// them, so every name they spell is skipped.
+pub fn lint_genera(morphemes: &Morphemes, config: &Config) -> Vec<Finding> {
+    let space = NameSpace::for_config(morphemes, config);
+
+    let mut entries = Vec::new();
+    let mut listed = HashSet::new();
+    let mut passed = HashSet::new();
+
+    for (prefix, morpheme) in morphemes.prefixes.iter().enumerate() {
+        for &(root, suffix) in space.genera_for(prefix) {
+            let indices = NameIndices {
+                prefix,
+                root,
+                suffix,
+                descriptor: 0,
+                subspecies: None,
+            };
+            let (root, suffix) = (space.root(&indices).text, space.suffix(&indices).text);
+            let acceptable = quality::is_acceptable(&space.genus(&indices), root, suffix, config);
+
+            for entry in [
+                ("prefix", morpheme.text.to_string()),
+                ("root", root.to_string()),
+                ("genus suffix", suffix.to_string()),
+                ("root and suffix", format!("{} + -{}", root, suffix)),
+            ] {
+                if acceptable {
+                    passed.insert(entry.clone());
+                }
+                if listed.insert(entry.clone()) {
+                    entries.push(entry);
+                }
+            }
+        }
+    }
+
+    let never = |table: &'static str, text: &str| !passed.contains(&(table, text.to_string()));
+    let mut findings = Vec::new();
+    for table in ["prefix", "root", "genus suffix", "root and suffix"] {
+        for (_, text) in entries.iter().filter(|(t, _)| *t == table) {
+            if !never(table, text) {
+                continue;
+            }
+            // A pair is not worth a finding of its own if its root or its
+            // suffix never passes at all.
+            if let Some((root, suffix)) = text.split_once(" + -") {
+                if never("root", root) || never("genus suffix", suffix) {
+                    continue;
+                }
+            }
+            findings.push(Finding {
+                table,
+                text: text.clone(),
+                problem: Problem::NeverAcceptable,
+            });
+        }
+    }
+
+    findings
+}
+
+fn check_words<'a>(
+    findings: &mut Vec<Finding>,
+    table: &'static str,
+    texts: impl Iterator<Item = &'a str>,
+    capitalised: bool,
+) {
+    let mut seen = HashSet::new();
+    let mut repeated = HashSet::new();
+
+    for text in texts {
+        let problem = if !seen.insert(text) {
+            repeated.insert(text).then_some(Problem::Duplicate)
+        } else {
+            spelling_problem(text, capitalised)
+        };
+        if let Some(problem) = problem {
+            findings.push(Finding {
+                table,
+                text: text.to_string(),
+                problem,
+            });
+        }
+    }
+}
+
+fn spelling_problem(text: &str, capitalised: bool) -> Option<Problem> {
+    if text.is_empty() {
+        return Some(Problem::Empty);
+    }
+    if text.contains('-') {
+        return Some(Problem::Hyphenated);
+    }
+    if let Some(c) = text.chars().find(|c| !c.is_ascii_alphabetic()) {
+        return Some(Problem::NotALetter(c));
+    }
+
+    let mut chars = text.chars();
+    let first = chars.next().is_some_and(|c| c.is_ascii_uppercase());
+    let cased = first == capitalised && chars.all(|c| c.is_ascii_lowercase());
+    (!cased).then_some(Problem::Capitalisation { capitalised })
+}
+
+fn ending_problem(descriptor: &SpeciesDescriptor) -> Option<Problem> {
+    let ending = descriptor.declension.ending();
+    let Some(stem) = descriptor.text.strip_suffix(ending) else {
+        return Some(Problem::WrongEnding { ending });
+    };
+
+    ENGLISH_ENDINGS
+        .iter()
+        .find(|&&english| stem.ends_with(english))
+        .map(|&ending| Problem::EnglishEnding { ending })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Overrides;
+    use crate::data::{Pack, THEMES};
+    use crate::scheme;
+
+    const PACK: &str = r#"
+roots = ["cephal", "ocul", "Pod", "ocul", "anth"]
+genus_suffixes = ["us", "-a"]
+
+[[prefixes]]
+text = "Macro"
+origin = "greek"
+category = "size"
+
+[[prefixes]]
+text = "acantho"
+origin = "greek"
+category = "form"
+
+[[species_descriptors]]
+text = "robusta"
+category = "size"
+declension = "us-a-um"
+
+[[species_descriptors]]
+text = "famousus"
+declension = "us-a-um"
+
+[[species_descriptors]]
+text = "velóx"
+"#;
+
+    fn problems(findings: &[Finding]) -> Vec<(&str, &str, Problem)> {
+        findings
+            .iter()
+            .map(|f| (f.table, f.text.as_str(), f.problem.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_tables() {
+        let pack = Pack::parse_unchecked(PACK, false).unwrap();
+        let morphemes = pack.morphemes();
+        let lower = Problem::Capitalisation { capitalised: false };
+
+        assert_eq!(
+            problems(&lint_tables(&morphemes)),
+            [
+                (
+                    "prefix",
+                    "acantho",
+                    Problem::Capitalisation { capitalised: true }
+                ),
+                ("root", "Pod", lower),
+                ("root", "ocul", Problem::Duplicate),
+                ("genus suffix", "-a", Problem::Hyphenated),
+                ("species descriptor", "velóx", Problem::NotALetter('ó')),
+                (
+                    "species descriptor",
+                    "robusta",
+                    Problem::WrongEnding { ending: "us" }
+                ),
+                (
+                    "species descriptor",
+                    "famousus",
+                    Problem::EnglishEnding { ending: "ous" }
+                ),
+            ]
+        );
+        assert!(Pack::parse(PACK, false).is_err());
+    }
+
+    #[test]
+    fn test_lint_genera() {
+        let pack = Pack::parse_unchecked(PACK, false).unwrap();
+        let morphemes = pack.morphemes();
+        let config = Config::default();
+
+        assert_eq!(
+            problems(&lint_genera(&morphemes, &config)),
+            [
+                ("prefix", "acantho", Problem::NeverAcceptable),
+                ("root", "anth", Problem::NeverAcceptable),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builtin_findings_are_known() {
+        let scheme = scheme::latest();
+        let findings = lint(&scheme.morphemes(), &scheme.config);
+
+        assert!(!findings.is_empty());
+        assert!(findings.iter().all(Finding::is_known), "{:?}", findings);
+        for theme in THEMES {
+            let overrides = Overrides {
+                theme: Some(theme),
+                ..Overrides::default()
+            };
+            let scheme = scheme.with_overrides(&overrides).unwrap();
+            let findings = lint_tables(&scheme.morphemes());
+            assert!(findings.iter().all(Finding::is_known), "{:?}", findings);
+        }
+    }
+
+    #[test]
+    fn test_builtin_tables() {
+        let findings = lint_tables(&Morphemes::new());
+
+        assert!(findings.contains(&Finding {
+            table: "species descriptor",
+            text: "copiousus".to_string(),
+            problem: Problem::EnglishEnding { ending: "ous" },
+        }));
+        assert!(findings.iter().all(|f| f.problem == Problem::Duplicate
+            || f.problem == Problem::EnglishEnding { ending: "ous" }));
+    }
+}
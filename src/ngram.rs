@@ -0,0 +1,301 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Character n-gram model of how names are spelled, trained from a word list.
+//!
+//! A model file is plain text: an `order` line followed by one tab-separated
+//! n-gram and count per line, where `^` pads the start of a word and `$`
+//! marks its end. Lines starting with `#` are comments.
+
+use crate::pronounceability::Breakdown;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+pub const BUILTIN_WORDS: &str = include_str!("data/genera.txt");
+const BUILTIN_MODEL: &str = include_str!("data/genera.ngram");
+
+pub const MAX_ORDER: usize = 5;
+
+/// Letters a model predicts: a to z and the end of the word.
+const OUTCOMES: f64 = 27.0;
+
+/// Letters less likely than this count as unlikely; surprisal is measured in
+/// multiples of theirs.
+const RARE: f64 = 0.01;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NgramModel {
+    order: usize,
+    counts: HashMap<String, u32>,
+    /// For every context, how often it was followed by a letter and by how
+    /// many different ones.
+    contexts: HashMap<String, (u32, u32)>,
+    total: u32,
+}
+
+/// The model trained on [`BUILTIN_WORDS`], which schemes with
+/// [`crate::pronounceability::Model::Ngram`] score with.
+pub fn builtin() -> &'static NgramModel {
+    static MODEL: OnceLock<NgramModel> = OnceLock::new();
+    MODEL.get_or_init(|| NgramModel::parse(BUILTIN_MODEL).expect("built-in model is valid"))
+}
+
+/// Reads the words of a word list: every word of every line, so that
+/// binomials train on both of theirs. Lines starting with `#` are skipped.
+pub fn read_words(text: &str) -> Vec<String> {
+    text.lines()
+        .filter(|line| !line.starts_with('#'))
+        .flat_map(str::split_whitespace)
+        .map(normalize)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn normalize(word: &str) -> String {
+    word.to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_lowercase())
+        .collect()
+}
+
+impl NgramModel {
+    pub fn train<S: AsRef<str>>(words: &[S], order: usize) -> Result<Self, String> {
+        if !(1..=MAX_ORDER).contains(&order) {
+            return Err(format!("Order must be between 1 and {}", MAX_ORDER));
+        }
+
+        let mut counts = HashMap::new();
+        for word in words {
+            let padded = pad(&normalize(word.as_ref()), order);
+            for end in order..=padded.len() {
+                for n in 1..=order {
+                    *counts.entry(padded[end - n..end].to_string()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        Self::from_counts(order, counts)
+    }
+
+    /// Sums the counts into the totals the model scores with, refusing
+    /// counts whose sums do not fit.
+    fn from_counts(order: usize, counts: HashMap<String, u32>) -> Result<Self, String> {
+        let overflow = || "Counts too large: their sum overflows".to_string();
+
+        let mut contexts = HashMap::new();
+        let mut total: u32 = 0;
+        for (gram, &count) in &counts {
+            if gram.len() == 1 {
+                total = total.checked_add(count).ok_or_else(overflow)?;
+            } else {
+                let context: &mut (u32, u32) = contexts
+                    .entry(gram[..gram.len() - 1].to_string())
+                    .or_insert((0, 0));
+                context.0 = context.0.checked_add(count).ok_or_else(overflow)?;
+                context.1 += 1;
+            }
+        }
+
+        Ok(Self {
+            order,
+            counts,
+            contexts,
+            total,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut order = None;
+        let mut counts = HashMap::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (gram, value) = line
+                .split_once('\t')
+                .ok_or_else(|| format!("Line {}: expected an n-gram and a count", idx + 1))?;
+            let value: u32 = value
+                .parse()
+                .map_err(|_| format!("Line {}: invalid count: {}", idx + 1, value))?;
+
+            match order {
+                None if gram == "order" => order = Some(value as usize),
+                None => return Err("Model must start with its order".to_string()),
+                Some(order) => {
+                    let valid = gram
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c == '^' || c == '$');
+                    if gram.is_empty() || gram.len() > order || !valid {
+                        return Err(format!("Line {}: invalid n-gram: {}", idx + 1, gram));
+                    }
+                    counts.insert(gram.to_string(), value);
+                }
+            }
+        }
+
+        match order {
+            Some(order) if (1..=MAX_ORDER).contains(&order) => Self::from_counts(order, counts),
+            Some(_) => Err(format!("Order must be between 1 and {}", MAX_ORDER)),
+            None => Err("Model must start with its order".to_string()),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read model {}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("Invalid model {}: {}", path.display(), e))
+    }
+
+    /// The model in its file format, n-grams sorted so that training on the
+    /// same words always writes the same file.
+    pub fn to_text(&self) -> String {
+        let mut grams: Vec<(&String, &u32)> = self.counts.iter().collect();
+        grams.sort();
+
+        let mut text = format!("# holotype n-gram model\norder\t{}\n", self.order);
+        for (gram, count) in grams {
+            text.push_str(&format!("{}\t{}\n", gram, count));
+        }
+        text
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Probability of the last letter of `gram` after the ones before it,
+    /// with Witten-Bell smoothing: each context passes on to the next shorter
+    /// one a share that grows with the variety of letters seen after it,
+    /// down to letter frequencies with add-one smoothing.
+    fn probability(&self, gram: &str) -> f64 {
+        let letter = &gram[gram.len() - 1..];
+        let count = |g: &str| self.counts.get(g).copied().unwrap_or(0) as f64;
+
+        let mut probability = (count(letter) + 1.0) / (self.total as f64 + OUTCOMES);
+        for start in (0..gram.len() - 1).rev() {
+            let context = &gram[start..gram.len() - 1];
+            if let Some(&(seen, variety)) = self.contexts.get(context) {
+                let (seen, variety) = (seen as f64, variety as f64);
+                probability = (count(&gram[start..]) + variety * probability) / (seen + variety);
+            }
+        }
+        probability
+    }
+
+    /// How surprising `word` is letter by letter: the average surprisal,
+    /// capped for each letter at that of a [`RARE`] one, and the share of
+    /// letters rarer than that.
+    pub fn breakdown(&self, word: &str) -> Breakdown {
+        let padded = pad(&normalize(word), self.order);
+        let rare = -log2(RARE);
+        let surprisals: Vec<f64> = (self.order..=padded.len())
+            .map(|end| -log2(self.probability(&padded[end - self.order..end])) / rare)
+            .collect();
+
+        let letters = surprisals.len() as f64;
+        let average = surprisals.iter().map(|s| s.min(1.0)).sum::<f64>() / letters;
+        let unlikely = surprisals.iter().filter(|&&s| s > 1.0).count() as f64 / letters;
+
+        Breakdown {
+            components: vec![
+                ("surprisal", average as f32),
+                ("unlikely letters", unlikely as f32),
+            ],
+        }
+    }
+}
+
+/// Base-2 logarithm of a positive `x` from basic arithmetic only. Names
+/// depend on scores, and `f64::log2` comes from the platform's maths
+/// library, which may round differently from one system to the next.
+fn log2(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    // ln(m) = 2 atanh((m - 1) / (m + 1)), which converges quickly for m in
+    // [1, 2).
+    let t = (mantissa - 1.0) / (mantissa + 1.0);
+    let t2 = t * t;
+    let mut term = t;
+    let mut ln = 0.0;
+    for k in 0..20 {
+        ln += term / (2 * k + 1) as f64;
+        term *= t2;
+    }
+
+    exponent as f64 + 2.0 * ln / std::f64::consts::LN_2
+}
+
+fn pad(word: &str, order: usize) -> String {
+    format!("{}{}$", "^".repeat(order - 1), word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_model_matches_word_list() {
+        let trained = NgramModel::train(&read_words(BUILTIN_WORDS), 3).unwrap();
+        assert_eq!(
+            trained.to_text(),
+            BUILTIN_MODEL,
+            "Retrain src/data/genera.ngram with `holotype train-model`"
+        );
+        assert_eq!(builtin(), &trained);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let model = NgramModel::train(&["Homo sapiens", "Canis lupus"], 2).unwrap();
+        assert_eq!(NgramModel::parse(&model.to_text()).unwrap(), model);
+        assert_eq!(model.order(), 2);
+    }
+
+    #[test]
+    fn test_scores_familiar_spellings_higher() {
+        let model = builtin();
+        let familiar = model.breakdown("Pelecanus").score();
+        let strange = model.breakdown("Xqzvkopt").score();
+
+        assert!(familiar > strange, "{} vs {}", familiar, strange);
+        assert!((0.0..=1.0).contains(&strange));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_models() {
+        assert!(NgramModel::parse("a\t3\n").is_err());
+        assert!(NgramModel::parse("order\t9\n").is_err());
+        assert!(NgramModel::parse("order\t2\nabc\t1\n").is_err());
+        assert!(NgramModel::parse("order\t2\nAb\t1\n").is_err());
+        assert!(NgramModel::parse("order\t2\nab\tmany\n").is_err());
+        assert!(NgramModel::parse("order\t2\na\t4294967295\nb\t1\n").is_err());
+        assert!(NgramModel::parse("order\t2\nab\t4294967295\nac\t1\n").is_err());
+        assert!(NgramModel::train(&["Homo"], 0).is_err());
+    }
+
+    #[test]
+    fn test_log2() {
+        for x in [0.001, 0.01, 0.037, 0.5, 1.0, 1.5, 3.0, 1024.0] {
+            assert!((log2(x) - x.log2()).abs() < 1e-12, "log2({})", x);
+        }
+    }
+}
@@ -15,8 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-fn is_vowel(c: char) -> bool {
-    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+/// Whether `c` is a vowel. y counts as one, since in Latinised Greek it
+/// spells upsilon, as in Cyano- and Poly-.
+pub(crate) fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
 }
 
 fn ends_with_vowel(s: &str) -> bool {
@@ -77,7 +79,29 @@ pub fn is_phonotactically_valid(genus: &str) -> bool {
     )
 }
 
-fn has_bad_consonant_cluster(s: &str) -> bool {
+/// Length of the longest run of consonants in `s`. The digraphs ch, ph, rh
+/// and th spell a single sound and count as one; y counts as a vowel.
+pub fn longest_consonant_run(s: &str) -> usize {
+    let chars: Vec<char> = s.to_lowercase().chars().collect();
+    let mut longest = 0;
+    let mut run = 0;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if is_vowel(c) || !c.is_alphabetic() {
+            run = 0;
+            continue;
+        }
+        let digraph = c == 'h' && i > 0 && matches!(chars[i - 1], 'c' | 'p' | 'r' | 't');
+        if !digraph {
+            run += 1;
+            longest = longest.max(run);
+        }
+    }
+
+    longest
+}
+
+pub fn has_bad_consonant_cluster(s: &str) -> bool {
     let chars: Vec<char> = s.to_lowercase().chars().collect();
 
     for i in 0..chars.len().saturating_sub(2) {
@@ -156,6 +180,22 @@ mod tests {
         assert!(!is_phonotactically_valid("Chronokx"));
     }
 
+    #[test]
+    fn test_consonant_runs() {
+        assert_eq!(longest_consonant_run("Neomorphus"), 2);
+        assert_eq!(longest_consonant_run("Streptopterus"), 3);
+        assert_eq!(longest_consonant_run("Anthracis"), 3);
+        assert_eq!(longest_consonant_run("Archaeoryx"), 2);
+        assert_eq!(longest_consonant_run("Heptalymphnx"), 4);
+    }
+
+    #[test]
+    fn test_bad_clusters() {
+        assert!(has_bad_consonant_cluster("Oligonthus"));
+        assert!(has_bad_consonant_cluster("Brachsus"));
+        assert!(!has_bad_consonant_cluster("Hydrocephalus"));
+    }
+
     #[test]
     fn test_yx_needs_vowel() {
         assert!(is_suffix_compatible("soma", "yx"));
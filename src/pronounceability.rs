@@ -15,42 +15,92 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-pub fn pronounceability_score(name: &str) -> f32 {
-    let name_lower = name.to_lowercase();
-    let chars: Vec<char> = name_lower.chars().collect();
+use crate::ngram::{self, NgramModel};
+use crate::phonotactics::is_vowel;
+use std::fmt;
+use std::sync::Arc;
 
-    if chars.is_empty() {
-        return 0.0;
-    }
+/// The models a pronounceability score can come from. Schemes freeze the
+/// model along with the threshold, since switching changes which names pass.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Model {
+    /// Raw consonant runs, vowel/consonant alternation, letter count and a
+    /// list of difficult substrings.
+    Heuristic,
+    /// Syllable structure: legal onsets, coda weight, hiatus and syllable
+    /// count.
+    Syllabic,
+    /// How familiar the spelling is to the built-in n-gram model.
+    Ngram,
+    /// How familiar the spelling is to a model trained by the user.
+    Trained(Arc<NgramModel>),
+}
 
-    let mut penalties = 0.0;
-    let mut max_penalties = 0.0;
+impl Model {
+    pub fn breakdown(&self, name: &str) -> Breakdown {
+        match self {
+            Model::Heuristic => heuristic_breakdown(name),
+            Model::Syllabic => syllabic_breakdown(name),
+            Model::Ngram => ngram::builtin().breakdown(name),
+            Model::Trained(model) => model.breakdown(name),
+        }
+    }
 
-    let (consonant_penalty, max_consonant) = check_consonant_clusters(&chars);
-    penalties += consonant_penalty;
-    max_penalties += max_consonant;
+    pub fn score(&self, name: &str) -> f32 {
+        self.breakdown(name).score()
+    }
+}
 
-    let (alternation_penalty, max_alternation) = check_alternation(&chars);
-    penalties += alternation_penalty;
-    max_penalties += max_alternation;
+/// The penalties a score is made of, each between 0 (easy) and 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakdown {
+    pub components: Vec<(&'static str, f32)>,
+}
 
-    let (length_penalty, max_length) = check_length(&chars);
-    penalties += length_penalty;
-    max_penalties += max_length;
+impl Breakdown {
+    pub fn score(&self) -> f32 {
+        if self.components.is_empty() {
+            return 1.0;
+        }
 
-    let (difficult_penalty, max_difficult) = check_difficult_combinations(&name_lower);
-    penalties += difficult_penalty;
-    max_penalties += max_difficult;
+        let penalties: f32 = self.components.iter().map(|&(_, penalty)| penalty).sum();
+        1.0 - penalties / self.components.len() as f32
+    }
+}
 
-    if max_penalties == 0.0 {
-        return 1.0;
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts: Vec<String> = self
+            .components
+            .iter()
+            .map(|(component, penalty)| format!("{} {:.2}", component, penalty))
+            .collect();
+        write!(f, "{}", parts.join(", "))
     }
+}
 
-    1.0 - (penalties / max_penalties)
+pub fn pronounceability_score(name: &str) -> f32 {
+    Model::Heuristic.score(name)
 }
 
-fn is_vowel(c: char) -> bool {
-    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+fn heuristic_breakdown(name: &str) -> Breakdown {
+    let name_lower = name.to_lowercase();
+    let chars: Vec<char> = name_lower.chars().collect();
+
+    if chars.is_empty() {
+        return Breakdown {
+            components: vec![("empty", 1.0)],
+        };
+    }
+
+    Breakdown {
+        components: vec![
+            ("consonants", check_consonant_clusters(&chars).0),
+            ("alternation", check_alternation(&chars).0),
+            ("length", check_length(&chars).0),
+            ("difficult", check_difficult_combinations(&name_lower).0),
+        ],
+    }
 }
 
 fn check_consonant_clusters(chars: &[char]) -> (f32, f32) {
@@ -133,6 +183,161 @@ fn check_difficult_combinations(name: &str) -> (f32, f32) {
     (penalty, 1.0)
 }
 
+/// Consonants written with two letters that are said as one.
+const CONSONANT_DIGRAPHS: &[&str] = &["ch", "ph", "rh", "th", "qu"];
+
+const DIPHTHONGS: &[&str] = &["ae", "oe", "au", "ei", "eu"];
+
+/// Clusters of more than one consonant that may begin a syllable anywhere:
+/// a stop or f followed by l or r, as in *ca-pra*.
+const ONSETS: &[&str] = &[
+    "bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "tr", "chl", "chr", "phl",
+    "phr", "thr",
+];
+
+/// Clusters that may only begin a word, as in *Pteris*, *Chthonius* or
+/// *Strix*. Inside a word they are split: *dac-ty-lus*, *ves-ti-tus*.
+const INITIAL_ONSETS: &[&str] = &[
+    "pt", "ps", "pn", "ct", "cn", "gn", "mn", "tm", "chth", "phth", "sc", "sp", "st", "sm", "scl",
+    "scr", "spl", "spr", "str", "sph", "sch", "squ",
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Syllable {
+    pub onset: String,
+    pub nucleus: String,
+    pub coda: String,
+}
+
+/// Splits `word` into letters and digraphs, each marked as vowel or not.
+fn segments(word: &str) -> Vec<(String, bool)> {
+    let lower: Vec<char> = word
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+
+    while i < lower.len() {
+        let pair: String = lower[i..(i + 2).min(lower.len())].iter().collect();
+        let vowel = is_vowel(lower[i]);
+
+        if pair.len() == 2
+            && (CONSONANT_DIGRAPHS.contains(&pair.as_str()) || DIPHTHONGS.contains(&pair.as_str()))
+        {
+            segments.push((pair, vowel));
+            i += 2;
+        } else {
+            segments.push((lower[i].to_string(), vowel));
+            i += 1;
+        }
+    }
+
+    segments
+}
+
+fn is_legal_onset(cluster: &[(String, bool)], initial: bool) -> bool {
+    let joined: String = cluster.iter().map(|(text, _)| text.as_str()).collect();
+    cluster.len() <= 1
+        || ONSETS.contains(&joined.as_str())
+        || (initial && INITIAL_ONSETS.contains(&joined.as_str()))
+}
+
+/// Syllabifies `word` by the maximal onset principle: consonants between two
+/// vowels start the next syllable as far as they form a legal onset, and
+/// the rest close the syllable before. Vowels that do not form a diphthong
+/// are in hiatus and belong to separate syllables.
+pub fn syllabify(word: &str) -> Vec<Syllable> {
+    let segments = segments(word);
+    let mut syllables: Vec<Syllable> = Vec::new();
+    let mut cluster: Vec<(String, bool)> = Vec::new();
+
+    for segment in segments {
+        if !segment.1 {
+            cluster.push(segment);
+            continue;
+        }
+
+        let split = match syllables.last() {
+            None => 0,
+            Some(_) => (0..cluster.len())
+                .find(|&i| is_legal_onset(&cluster[i..], false))
+                .unwrap_or(cluster.len()),
+        };
+        if let Some(previous) = syllables.last_mut() {
+            previous
+                .coda
+                .extend(cluster[..split].iter().map(|(text, _)| text.as_str()));
+        }
+        syllables.push(Syllable {
+            onset: cluster[split..]
+                .iter()
+                .map(|(text, _)| text.as_str())
+                .collect(),
+            nucleus: segment.0,
+            coda: String::new(),
+        });
+        cluster.clear();
+    }
+
+    let rest: String = cluster.iter().map(|(text, _)| text.as_str()).collect();
+    match syllables.last_mut() {
+        Some(last) => last.coda.push_str(&rest),
+        None if !rest.is_empty() => syllables.push(Syllable {
+            onset: rest,
+            ..Syllable::default()
+        }),
+        None => {}
+    }
+
+    syllables
+}
+
+fn syllabic_breakdown(name: &str) -> Breakdown {
+    let syllables = syllabify(name);
+    if syllables.is_empty() {
+        return Breakdown {
+            components: vec![("empty", 1.0)],
+        };
+    }
+
+    let mut onsets = 0.0;
+    let mut codas = 0.0;
+    let mut hiatus = 0.0;
+
+    for (idx, syllable) in syllables.iter().enumerate() {
+        let onset = segments(&syllable.onset);
+        if syllable.nucleus.is_empty() || !is_legal_onset(&onset, idx == 0) {
+            onsets += 0.5;
+        }
+        codas += match segments(&syllable.coda).len() {
+            0 | 1 => 0.0,
+            2 => 0.25,
+            _ => 0.6,
+        };
+        if idx > 0 && onset.is_empty() {
+            hiatus += 0.2;
+        }
+    }
+
+    let length = match syllables.len() {
+        0..=5 => 0.0,
+        6 => 0.2,
+        7 => 0.5,
+        _ => 1.0,
+    };
+
+    Breakdown {
+        components: vec![
+            ("onsets", f32::min(onsets, 1.0)),
+            ("codas", f32::min(codas, 1.0)),
+            ("hiatus", f32::min(hiatus, 1.0)),
+            ("length", length),
+        ],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,4 +370,49 @@ mod tests {
         assert!(score < 0.9, "Expected score < 0.9, got {}", score);
         assert!(pronounceability_score("Pterodactyl") > 0.5);
     }
+
+    #[test]
+    fn test_heuristic_components() {
+        let breakdown = Model::Heuristic.breakdown("Strptxthclm");
+        assert_eq!(breakdown.components.len(), 4);
+        assert_eq!(breakdown.score(), pronounceability_score("Strptxthclm"));
+    }
+
+    #[test]
+    fn test_syllabify() {
+        let parts = |word: &str| -> Vec<String> {
+            syllabify(word)
+                .iter()
+                .map(|s| format!("{}|{}|{}", s.onset, s.nucleus, s.coda))
+                .collect()
+        };
+
+        assert_eq!(parts("Neomorphus"), ["n|e|", "|o|", "m|o|r", "ph|u|s"]);
+        assert_eq!(
+            parts("Pterodactylus"),
+            ["pt|e|", "r|o|", "d|a|c", "t|y|", "l|u|s"]
+        );
+        assert_eq!(
+            parts("Archaeoptera"),
+            ["|a|r", "ch|ae|", "|o|p", "t|e|", "r|a|"]
+        );
+        assert_eq!(parts("Chthonius"), ["chth|o|", "n|i|", "|u|s"]);
+        assert_eq!(parts("Strix"), ["str|i|x"]);
+    }
+
+    #[test]
+    fn test_syllabic_penalties() {
+        let easy = Model::Syllabic.breakdown("Neomorphus");
+        assert!(easy.score() > 0.9, "{}", easy);
+
+        let hiatus = Model::Syllabic.breakdown("Enneaacousoma");
+        assert!(hiatus.score() < 0.75, "{}", hiatus);
+        assert!(hiatus.components.contains(&("length", 1.0)));
+
+        let onset = Model::Syllabic.breakdown("Rtanus");
+        assert!(onset.components.contains(&("onsets", 0.5)));
+
+        let coda = Model::Syllabic.breakdown("Lymphnx");
+        assert!(coda.components.contains(&("codas", 0.6)), "{}", coda);
+    }
 }
@@ -0,0 +1,216 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! The quality filter deciding which genera may be issued.
+
+use crate::blocklist;
+use crate::config::Config;
+use crate::phonotactics::{
+    has_bad_consonant_cluster, is_phonotactically_valid, is_suffix_compatible,
+    longest_consonant_run,
+};
+use crate::pronounceability::Breakdown;
+use std::fmt;
+
+/// A rule of the quality filter that a genus broke.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rejection {
+    TooLong { length: usize, max: usize },
+    Unpronounceable { breakdown: Breakdown, min: f32 },
+    BadEnding,
+    BannedCluster,
+    ConsonantRun { length: usize, max: usize },
+    SuffixMismatch { root: String, suffix: String },
+    Blocked { word: String, entry: String },
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rejection::TooLong { length, max } => {
+                write!(f, "{} letters long (at most {})", length, max)
+            }
+            Rejection::Unpronounceable { breakdown, min } => write!(
+                f,
+                "pronounceability {:.2} (at least {:.2}; {})",
+                breakdown.score(),
+                min,
+                breakdown
+            ),
+            Rejection::BadEnding => write!(f, "ending not allowed in Latin"),
+            Rejection::BannedCluster => write!(f, "banned consonant cluster"),
+            Rejection::ConsonantRun { length, max } => {
+                write!(f, "{} consonants in a row (at most {})", length, max)
+            }
+            Rejection::SuffixMismatch { root, suffix } => {
+                write!(f, "suffix -{} cannot follow root {}", suffix, root)
+            }
+            Rejection::Blocked { word, entry } => {
+                write!(f, "{} contains blocked \"{}\"", word, entry)
+            }
+        }
+    }
+}
+
+/// Every rule `genus`, built from `root` and `suffix`, breaks under
+/// `config`. Only length and pronounceability are checked unless the config
+/// enforces phonotactics.
+pub fn rejections(genus: &str, root: &str, suffix: &str, config: &Config) -> Vec<Rejection> {
+    let mut rejections = Vec::new();
+
+    let length = genus.len();
+    if length > config.max_genus_length {
+        rejections.push(Rejection::TooLong {
+            length,
+            max: config.max_genus_length,
+        });
+    }
+
+    let breakdown = config.pronounceability.breakdown(genus);
+    if breakdown.score() < config.min_pronounceability_score {
+        rejections.push(Rejection::Unpronounceable {
+            breakdown,
+            min: config.min_pronounceability_score,
+        });
+    }
+
+    if !config.enforce_phonotactics {
+        return rejections;
+    }
+
+    if !is_phonotactically_valid(genus) {
+        rejections.push(Rejection::BadEnding);
+    }
+    if has_bad_consonant_cluster(genus) {
+        rejections.push(Rejection::BannedCluster);
+    }
+    let run = longest_consonant_run(genus);
+    if run > config.max_consonant_cluster {
+        rejections.push(Rejection::ConsonantRun {
+            length: run,
+            max: config.max_consonant_cluster,
+        });
+    }
+    if !is_suffix_compatible(root, suffix) {
+        rejections.push(Rejection::SuffixMismatch {
+            root: root.to_string(),
+            suffix: suffix.to_string(),
+        });
+    }
+
+    rejections
+}
+
+/// Whether `genus` passes the filter. Same verdict as [`rejections`], but
+/// stops at the first rule broken, since generation asks on every try.
+pub fn is_acceptable(genus: &str, root: &str, suffix: &str, config: &Config) -> bool {
+    if genus.len() > config.max_genus_length
+        || config.pronounceability.score(genus) < config.min_pronounceability_score
+    {
+        return false;
+    }
+
+    !config.enforce_phonotactics
+        || (is_phonotactically_valid(genus)
+            && !has_bad_consonant_cluster(genus)
+            && longest_consonant_run(genus) <= config.max_consonant_cluster
+            && is_suffix_compatible(root, suffix))
+}
+
+/// The rules that concern the whole of `name` rather than its genus: every
+/// word, genus and epithets alike, that contains an entry of the built-in
+/// blocklist or of the config's own.
+pub fn name_rejections(name: &str, config: &Config) -> Vec<Rejection> {
+    let lists = config
+        .builtin_blocklist
+        .then(|| blocklist::builtin(config.whole_word_blocklist))
+        .into_iter()
+        .chain(config.blocklist.as_deref());
+
+    let mut rejections = Vec::new();
+    for list in lists {
+        for word in name.split_whitespace() {
+            if let Some(entry) = list.find(word) {
+                rejections.push(Rejection::Blocked {
+                    word: word.to_string(),
+                    entry: entry.to_string(),
+                });
+            }
+        }
+    }
+    rejections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_phonotactics_are_optional() {
+        let lax = Config {
+            enforce_phonotactics: false,
+            ..Config::default()
+        };
+        let strict = Config::default();
+
+        assert!(is_acceptable("Melanonthus", "anth", "us", &lax));
+        assert_eq!(
+            rejections("Melanonthus", "anth", "us", &strict),
+            vec![Rejection::BannedCluster]
+        );
+    }
+
+    #[test]
+    fn test_lists_every_rule() {
+        let config = Config::default();
+        let found = rejections("Gastrmarx", "gastr", "ax", &config);
+
+        assert!(found.contains(&Rejection::BadEnding));
+        assert!(found.contains(&Rejection::ConsonantRun { length: 4, max: 3 }));
+        assert!(found.contains(&Rejection::SuffixMismatch {
+            root: "gastr".to_string(),
+            suffix: "ax".to_string()
+        }));
+        assert!(is_acceptable("Neomorphus", "morph", "us", &config));
+    }
+
+    #[test]
+    fn test_blocks_every_word() {
+        let list = Arc::new(blocklist::Blocklist::new(&["morf", "rufa"]));
+        let config = Config {
+            blocklist: Some(list),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            name_rejections("Neomorphus rufa", &config),
+            vec![
+                Rejection::Blocked {
+                    word: "Neomorphus".to_string(),
+                    entry: "morf".to_string()
+                },
+                Rejection::Blocked {
+                    word: "rufa".to_string(),
+                    entry: "rufa".to_string()
+                },
+            ]
+        );
+        assert!(name_rejections("Neomorphus rufus", &Config::default()).is_empty());
+        assert_eq!(name_rejections("Shytus rufus", &Config::default()).len(), 1);
+    }
+}
@@ -0,0 +1,618 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Registry of naming schemes.
+//!
+//! A scheme freezes everything a name depends on: the algorithm, the
+//! `Config` thresholds and how much of each morpheme table it uses. Tables
+//! may only grow at the end; a scheme keeps reading its own leading slice
+//! and records a fingerprint of it, which the tests check. Any change that
+//! would alter existing names belongs in a new scheme appended to
+//! [`SCHEMES`], never in an edit to an old one.
+
+use crate::config::{Config, Layout, Overrides};
+use crate::data::{
+    Morphemes, Pack, Root, Theme, BLOCKLIST, GENUS_SUFFIXES, PREFIXES, ROOTS, SPECIES_DESCRIPTORS,
+    SUBSPECIES_DESCRIPTORS, WHOLE_WORDS,
+};
+use crate::key::Key;
+use crate::pronounceability::Model;
+use crate::quality::Rejection;
+use crate::{generator, legacy};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::sync::Arc;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Algorithm {
+    /// Holotype 0.1: overlapping decimal date packing and a 64-bit Feistel
+    /// network reduced with `%`. Decoding regenerates every date.
+    Legacy,
+    /// Day-ordinal packing and a cycle-walking permutation over the exact
+    /// name space. Decoding inverts the permutation.
+    Permutation,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tables {
+    pub prefixes: usize,
+    pub roots: usize,
+    pub genus_suffixes: usize,
+    pub species_descriptors: usize,
+    pub subspecies_descriptors: usize,
+    pub fingerprint: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Scheme {
+    pub id: &'static str,
+    pub algorithm: Algorithm,
+    pub config: Config,
+    pub tables: Tables,
+    /// Tables loaded from a pack, used instead of the built-in ones.
+    pub pack: Option<Arc<Pack>>,
+    /// Roots on a theme, used instead of the scheme's own.
+    pub theme: Option<&'static Theme>,
+}
+
+const V1_CONFIG: Config = Config {
+    epoch_year: 2000,
+    year_start: 2000,
+    year_end: 2099,
+    number_min: 1,
+    number_max: 99,
+    numbers_per_block: 99,
+    minutes_per_slot: 3,
+    layout: Layout::Domain,
+    trinomials: false,
+    gender_agreement: false,
+    match_origins: false,
+    pure_origins: false,
+    categories: None,
+    enforce_phonotactics: false,
+    max_consonant_cluster: 3,
+    pronounceability: Model::Heuristic,
+    min_pronounceability_score: 0.3,
+    max_genus_length: 18,
+    builtin_blocklist: false,
+    whole_word_blocklist: false,
+    blocklist: None,
+};
+
+const V3_CONFIG: Config = Config {
+    gender_agreement: true,
+    ..V1_CONFIG
+};
+
+const V4_CONFIG: Config = Config {
+    enforce_phonotactics: true,
+    ..V3_CONFIG
+};
+
+// Syllabic scores run higher than heuristic ones; nothing scored below 0.3
+// under either model, so the threshold moves up to where it bites.
+const V5_CONFIG: Config = Config {
+    pronounceability: Model::Syllabic,
+    min_pronounceability_score: 0.75,
+    ..V4_CONFIG
+};
+
+// About 1% of genera score below 0.5 under the built-in n-gram model.
+const V6_CONFIG: Config = Config {
+    pronounceability: Model::Ngram,
+    min_pronounceability_score: 0.5,
+    ..V5_CONFIG
+};
+
+const V7_CONFIG: Config = Config {
+    builtin_blocklist: true,
+    ..V6_CONFIG
+};
+
+// Matching origins leaves too few names for two tries at every 3-minute
+// slot, so moments fall into 5-minute ones.
+const V8_CONFIG: Config = Config {
+    match_origins: true,
+    minutes_per_slot: 5,
+    ..V7_CONFIG
+};
+
+// Tries sit in fixed strides of the name space, so widening the years or
+// the numbers no longer renames the tuples whose first try was turned down.
+// Pairs beyond the binomial stride and moments are named with trinomials,
+// which leaves room for numbers up to 999 by default. The blocklist entries
+// that honest Latin spells inside longer words only block whole words.
+const V9_CONFIG: Config = Config {
+    number_max: 999,
+    minutes_per_slot: 3,
+    layout: Layout::Strided { attempts: 6 },
+    whole_word_blocklist: true,
+    ..V8_CONFIG
+};
+
+const V1_TABLES: Tables = Tables {
+    prefixes: 150,
+    roots: 278,
+    genus_suffixes: 16,
+    species_descriptors: 141,
+    subspecies_descriptors: 0,
+    fingerprint: 0xcd3899efa422084c,
+};
+
+// The tables of v1 and v2 and the subspecies table, which arrived after them
+// and only feeds trinomials; the fingerprint also covers the declensions that
+// agreement reads.
+const V3_TABLES: Tables = Tables {
+    subspecies_descriptors: 100,
+    fingerprint: 0x0d12a87d1fd3d1b3,
+    ..V1_TABLES
+};
+
+// Same tables as v3; the fingerprint also covers the built-in blocklist.
+const V7_TABLES: Tables = Tables {
+    fingerprint: 0x7c2217cfd97177f2,
+    ..V3_TABLES
+};
+
+// Same tables as v7; the fingerprint also covers the origins of the roots
+// and suffixes.
+const V8_TABLES: Tables = Tables {
+    fingerprint: 0x9dc69af6bccc22a0,
+    ..V7_TABLES
+};
+
+// Same tables as v8; the fingerprint also covers the whole-word entries of
+// the blocklist.
+const V9_TABLES: Tables = Tables {
+    fingerprint: 0x580eccbaee21acaa,
+    ..V8_TABLES
+};
+
+pub const SCHEMES: &[Scheme] = &[
+    Scheme {
+        id: "v1",
+        algorithm: Algorithm::Legacy,
+        config: V1_CONFIG,
+        tables: V1_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v2",
+        algorithm: Algorithm::Permutation,
+        config: V1_CONFIG,
+        tables: V1_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v3",
+        algorithm: Algorithm::Permutation,
+        config: V3_CONFIG,
+        tables: V3_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v4",
+        algorithm: Algorithm::Permutation,
+        config: V4_CONFIG,
+        tables: V3_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v5",
+        algorithm: Algorithm::Permutation,
+        config: V5_CONFIG,
+        tables: V3_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v6",
+        algorithm: Algorithm::Permutation,
+        config: V6_CONFIG,
+        tables: V3_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v7",
+        algorithm: Algorithm::Permutation,
+        config: V7_CONFIG,
+        tables: V7_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v8",
+        algorithm: Algorithm::Permutation,
+        config: V8_CONFIG,
+        tables: V8_TABLES,
+        pack: None,
+        theme: None,
+    },
+    Scheme {
+        id: "v9",
+        algorithm: Algorithm::Permutation,
+        config: V9_CONFIG,
+        tables: V9_TABLES,
+        pack: None,
+        theme: None,
+    },
+];
+
+pub fn find(id: &str) -> Option<&'static Scheme> {
+    SCHEMES.iter().find(|scheme| scheme.id == id)
+}
+
+pub fn latest() -> &'static Scheme {
+    &SCHEMES[SCHEMES.len() - 1]
+}
+
+pub fn ids() -> Vec<&'static str> {
+    SCHEMES.iter().map(|scheme| scheme.id).collect()
+}
+
+impl Scheme {
+    pub fn morphemes(&self) -> Morphemes<'_> {
+        if let Some(pack) = &self.pack {
+            return pack.morphemes();
+        }
+
+        Morphemes {
+            prefixes: PREFIXES[..self.tables.prefixes].to_vec(),
+            roots: self
+                .theme
+                .map_or(&ROOTS[..self.tables.roots], |theme| theme.roots)
+                .to_vec(),
+            genus_suffixes: GENUS_SUFFIXES[..self.tables.genus_suffixes].to_vec(),
+            species_descriptors: SPECIES_DESCRIPTORS[..self.tables.species_descriptors].to_vec(),
+            subspecies_descriptors: SUBSPECIES_DESCRIPTORS[..self.tables.subspecies_descriptors]
+                .to_vec(),
+            pack_hash: self.theme.map(|theme| theme.fingerprint),
+        }
+    }
+
+    /// Whether decoding is fast enough to try this scheme on every name.
+    /// Legacy names are only looked up when the scheme is asked for.
+    pub fn decodes_instantly(&self) -> bool {
+        self.algorithm != Algorithm::Legacy
+    }
+
+    /// Whether a name can only stand for a moment if it stands for no
+    /// numbered work, because moments have names of their own.
+    pub fn keeps_moments_apart(&self) -> bool {
+        matches!(self.config.layout, Layout::Strided { .. })
+    }
+
+    /// Returns a copy of the scheme with `overrides` applied to its config.
+    pub fn with_overrides(&self, overrides: &Overrides) -> Result<Scheme, String> {
+        if self.algorithm == Algorithm::Legacy && !overrides.is_empty() {
+            return Err(format!("Scheme {} cannot be configured", self.id));
+        }
+
+        Ok(Scheme {
+            config: overrides.apply(&self.config)?,
+            pack: overrides.pack.clone().or_else(|| self.pack.clone()),
+            theme: overrides.theme.or(self.theme),
+            ..self.clone()
+        })
+    }
+
+    /// Whether names can be drawn from a private namespace with a [`Key`].
+    pub fn supports_keys(&self) -> bool {
+        self.algorithm != Algorithm::Legacy
+    }
+
+    fn check_key(&self, key: Option<&Key>) -> Result<(), String> {
+        if key.is_some() && !self.supports_keys() {
+            return Err(format!("Scheme {} does not support keys", self.id));
+        }
+        Ok(())
+    }
+
+    pub fn generate_name(
+        &self,
+        date: NaiveDate,
+        number: u32,
+        salt: &str,
+        key: Option<&Key>,
+        morphemes: &Morphemes,
+        config: &Config,
+    ) -> Result<String, String> {
+        self.check_key(key)?;
+
+        match self.algorithm {
+            Algorithm::Legacy => {
+                generator::check_tuple(date, number, config)?;
+                Ok(legacy::generate_name(date, number, salt, morphemes, config))
+            }
+            Algorithm::Permutation => {
+                generator::generate_name_keyed(date, number, salt, key, morphemes, config)
+            }
+        }
+    }
+
+    pub fn generate_timed_name(
+        &self,
+        datetime: NaiveDateTime,
+        salt: &str,
+        key: Option<&Key>,
+        morphemes: &Morphemes,
+        config: &Config,
+    ) -> Result<String, String> {
+        self.check_key(key)?;
+
+        match self.algorithm {
+            Algorithm::Legacy => Err(format!("Scheme {} does not support times", self.id)),
+            Algorithm::Permutation => {
+                generator::generate_timed_name(datetime, salt, key, morphemes, config)
+            }
+        }
+    }
+
+    /// The names the quality filter passed over before [`Self::generate_name`]
+    /// settled on one, each with the rules it broke.
+    pub fn rejected_names(
+        &self,
+        date: NaiveDate,
+        number: u32,
+        salt: &str,
+        key: Option<&Key>,
+        morphemes: &Morphemes,
+        config: &Config,
+    ) -> Result<Vec<(String, Vec<Rejection>)>, String> {
+        self.check_key(key)?;
+
+        match self.algorithm {
+            Algorithm::Legacy => Err(format!("Scheme {} cannot list rejected names", self.id)),
+            Algorithm::Permutation => {
+                generator::rejected_names(date, number, salt, key, morphemes, config)
+            }
+        }
+    }
+
+    pub fn rejected_timed_names(
+        &self,
+        datetime: NaiveDateTime,
+        salt: &str,
+        key: Option<&Key>,
+        morphemes: &Morphemes,
+        config: &Config,
+    ) -> Result<Vec<(String, Vec<Rejection>)>, String> {
+        self.check_key(key)?;
+
+        match self.algorithm {
+            Algorithm::Legacy => Err(format!("Scheme {} cannot list rejected names", self.id)),
+            Algorithm::Permutation => {
+                generator::rejected_timed_names(datetime, salt, key, morphemes, config)
+            }
+        }
+    }
+
+    pub fn decode_candidates(
+        &self,
+        name: &str,
+        salt: &str,
+        key: Option<&Key>,
+        morphemes: &Morphemes,
+        config: &Config,
+    ) -> Result<Vec<(NaiveDate, u32)>, String> {
+        self.check_key(key)?;
+
+        Ok(match self.algorithm {
+            Algorithm::Legacy => legacy::decode_candidates(name, salt, morphemes, config),
+            Algorithm::Permutation => {
+                generator::decode_candidates_keyed(name, salt, key, morphemes, config)
+            }
+        })
+    }
+
+    pub fn decode_timed_candidates(
+        &self,
+        name: &str,
+        salt: &str,
+        key: Option<&Key>,
+        morphemes: &Morphemes,
+        config: &Config,
+    ) -> Result<Vec<NaiveDateTime>, String> {
+        self.check_key(key)?;
+
+        Ok(match self.algorithm {
+            Algorithm::Legacy => Vec::new(),
+            Algorithm::Permutation => {
+                generator::decode_timed_candidates(name, salt, key, morphemes, config)
+            }
+        })
+    }
+}
+
+/// FNV-1a over every field of the tables that can influence a name under
+/// `config`.
+pub fn table_fingerprint(morphemes: &Morphemes, config: &Config) -> u64 {
+    let mut hash = FNV_OFFSET;
+    let mut feed = |bytes: &[u8]| fnv(&mut hash, bytes);
+
+    for prefix in &morphemes.prefixes {
+        feed(prefix.text.as_bytes());
+        feed(&[prefix.origin as u8, prefix.category as u8]);
+    }
+    for root in &morphemes.roots {
+        feed(root.text.as_bytes());
+        if config.match_origins {
+            feed(&[root.origin.map_or(0xff, |o| o as u8)]);
+        }
+    }
+    for suffix in &morphemes.genus_suffixes {
+        feed(suffix.text.as_bytes());
+        if config.match_origins {
+            feed(&[suffix.origin.map_or(0xff, |o| o as u8)]);
+        }
+    }
+    for descriptor in &morphemes.species_descriptors {
+        feed(descriptor.text.as_bytes());
+        feed(&[descriptor.category.map_or(0xff, |c| c as u8)]);
+        if config.gender_agreement {
+            feed(&[descriptor.declension as u8]);
+        }
+    }
+    for subspecies in &morphemes.subspecies_descriptors {
+        feed(subspecies.text.as_bytes());
+        if config.gender_agreement {
+            feed(&[subspecies.declension as u8]);
+        }
+    }
+
+    if config.builtin_blocklist {
+        for entry in BLOCKLIST {
+            feed(entry.as_bytes());
+        }
+        if config.whole_word_blocklist {
+            for entry in WHOLE_WORDS {
+                feed(entry.as_bytes());
+            }
+        }
+    }
+
+    hash
+}
+
+/// FNV-1a over the spellings and origins of the roots of a theme.
+pub fn roots_fingerprint(roots: &[Root]) -> u64 {
+    let mut hash = FNV_OFFSET;
+    for root in roots {
+        fnv(&mut hash, root.text.as_bytes());
+        fnv(&mut hash, &[root.origin.map_or(0xff, |o| o as u8)]);
+    }
+    hash
+}
+
+pub(crate) const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+/// Feeds `bytes` and a separator to an FNV-1a hash.
+pub(crate) fn fnv(hash: &mut u64, bytes: &[u8]) {
+    for &b in bytes.iter().chain(&[0xff]) {
+        *hash ^= b as u64;
+        *hash = hash.wrapping_mul(0x100000001b3);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_are_frozen() {
+        for scheme in SCHEMES {
+            assert_eq!(
+                table_fingerprint(&scheme.morphemes(), &scheme.config),
+                scheme.tables.fingerprint,
+                "Tables used by scheme {} have changed; add a new scheme instead",
+                scheme.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_themes_are_frozen() {
+        for theme in crate::data::THEMES {
+            assert_eq!(
+                roots_fingerprint(theme.roots),
+                theme.fingerprint,
+                "Roots of theme {} have changed; add a new theme instead",
+                theme.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_ids_are_unique() {
+        let ids = ids();
+        for (i, id) in ids.iter().enumerate() {
+            assert!(!ids[i + 1..].contains(id), "Duplicate scheme id {}", id);
+        }
+    }
+
+    #[test]
+    fn test_default_config_matches_latest() {
+        assert_eq!(Config::default(), latest().config);
+    }
+
+    #[test]
+    fn test_legacy_rejects_keys() {
+        let legacy = find("v1").unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+        let key = Key::from_bytes([7; 16]);
+
+        assert!(legacy
+            .generate_name(date, 7, "", Some(&key), &legacy.morphemes(), &legacy.config)
+            .is_err());
+    }
+
+    #[test]
+    fn test_with_overrides() {
+        let v2 = find("v2").unwrap();
+        let years = |text, epoch| Overrides::parse(Some(text), epoch, None).unwrap();
+
+        let wide = v2.with_overrides(&years("1900-2199", None)).unwrap();
+        assert_eq!(wide.config.epoch_year, 2000);
+        assert_eq!(wide.config.year_start, 1900);
+
+        let later = v2.with_overrides(&years("2100-2199", None)).unwrap();
+        assert_eq!(later.config.epoch_year, 2100);
+
+        let numbers = Overrides::parse(None, None, Some("1-999")).unwrap();
+        let counted = v2.with_overrides(&numbers).unwrap();
+        assert_eq!(counted.config.number_max, 999);
+        assert_eq!(counted.config.year_start, 2000);
+
+        assert!(v2.with_overrides(&years("1900-2199", Some(1800))).is_err());
+        assert!(v2.with_overrides(&years("0-2199", None)).is_err());
+        assert!(find("v1")
+            .unwrap()
+            .with_overrides(&years("1900-2199", None))
+            .is_err());
+        assert!(find("v1")
+            .unwrap()
+            .with_overrides(&Overrides::default())
+            .is_ok());
+        assert!(Overrides::parse(Some("1900"), None, None).is_err());
+        assert!(Overrides::parse(None, None, Some("1-")).is_err());
+    }
+
+    #[test]
+    fn test_schemes_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+        for scheme in SCHEMES.iter().filter(|s| s.decodes_instantly()) {
+            let morphemes = scheme.morphemes();
+            let name = scheme
+                .generate_name(date, 7, "patch", None, &morphemes, &scheme.config)
+                .unwrap();
+
+            assert_eq!(
+                scheme
+                    .decode_candidates(&name, "patch", None, &morphemes, &scheme.config)
+                    .unwrap(),
+                [(date, 7)],
+                "{}",
+                scheme.id
+            );
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Telling apart names that are easy to confuse, and the ledger of names
+//! already issued that new ones are held against.
+
+use std::io::Write;
+use std::path::Path;
+
+/// Names at most this many edits apart are too close.
+pub const MAX_DISTANCE: usize = 2;
+
+/// Levenshtein distance between `a` and `b`, in characters.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// How `name` sounds, word by word: spellings Latin reads alike are merged
+/// (ph as f, th as t, y as i, k and ch as c, ae and oe as e), doubled letters
+/// collapse, and only the first letter and the consonants of each word are
+/// kept, so that names differing only in their vowels, such as "Neomorphus
+/// lucidus" and "Neomorphis lucidus", share a key.
+pub fn phonetic_key(name: &str) -> String {
+    name.split_whitespace()
+        .map(word_key)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn word_key(word: &str) -> String {
+    let word = word
+        .to_lowercase()
+        .replace("ph", "f")
+        .replace("th", "t")
+        .replace("rh", "r")
+        .replace("ch", "c")
+        .replace("ae", "e")
+        .replace("oe", "e")
+        .replace('y', "i")
+        .replace('k', "c")
+        .replace('x', "cs");
+
+    let mut key = String::new();
+    let mut last = None;
+    for (i, c) in word.chars().enumerate() {
+        if Some(c) != last && (i == 0 || !"aeiou".contains(c)) {
+            key.push(c);
+        }
+        last = Some(c);
+    }
+    key
+}
+
+/// Whether `a` and `b` are distinct names that could be mistaken for each
+/// other: they sound alike or are a few typos apart.
+pub fn is_similar(a: &str, b: &str) -> bool {
+    let (a, b) = (a.to_lowercase(), b.to_lowercase());
+    a != b && (edit_distance(&a, &b) <= MAX_DISTANCE || phonetic_key(&a) == phonetic_key(&b))
+}
+
+/// Names already issued, one per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ledger {
+    names: Vec<String>,
+}
+
+impl Ledger {
+    pub fn parse(text: &str) -> Self {
+        Self {
+            names: text
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Reads the ledger at `path`; a ledger that does not exist yet is empty.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Cannot read ledger {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Records `name` at the end of the ledger at `path` unless it is there
+    /// already.
+    pub fn append(&self, path: &Path, name: &str) -> Result<(), String> {
+        if self.contains(name) {
+            return Ok(());
+        }
+        write_line(path, name)
+    }
+
+    /// Notes in the ledger at `path` that `skipped` was not issued for being
+    /// too close to `issued`. Notes are comments, so they never count as
+    /// issued names.
+    pub fn record_skip(&self, path: &Path, skipped: &str, issued: &str) -> Result<(), String> {
+        write_line(
+            path,
+            &format!("# skipped {}, too close to {}", skipped, issued),
+        )
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|issued| issued == name)
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The first issued name that `name` could be mistaken for. A name is
+    /// never too close to itself, so issued names still decode.
+    pub fn find_similar(&self, name: &str) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|issued| is_similar(issued, name))
+            .map(String::as_str)
+    }
+}
+
+fn write_line(path: &Path, line: &str) -> Result<(), String> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line))
+        .map_err(|e| format!("Cannot write ledger {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("Neomorphus", "Neomorphis"), 1);
+        assert_eq!(edit_distance("lucidus", "lucidus"), 0);
+    }
+
+    #[test]
+    fn test_phonetic_key() {
+        assert_eq!(phonetic_key("Neomorphus lucidus"), "nmrfs lcds");
+        assert_eq!(
+            phonetic_key("Neomorphis lucidus"),
+            phonetic_key("Neomorfus lucidus")
+        );
+        assert_eq!(phonetic_key("Haemocyttus"), phonetic_key("Hemocitus"));
+        assert_ne!(phonetic_key("Neomorphus"), phonetic_key("Neomorpha"));
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert!(is_similar("Neomorphus lucidus", "Neomorphis lucidus"));
+        assert!(is_similar("Pterichthys agilis", "Pterychtys agiles"));
+        assert!(!is_similar("Neomorphus lucidus", "Neomorphus lucidus"));
+        assert!(!is_similar("Neomorphus lucidus", "Macrocardus rufus"));
+    }
+
+    #[test]
+    fn test_ledger() {
+        let ledger = Ledger::parse("# issued\nNeomorphus lucidus\n\nMacrocardus rufus\n");
+
+        assert_eq!(ledger.names().len(), 2);
+        assert_eq!(
+            ledger.find_similar("Neomorphis lucidus"),
+            Some("Neomorphus lucidus")
+        );
+        assert_eq!(ledger.find_similar("Neomorphus lucidus"), None);
+        assert_eq!(ledger.find_similar("Squamidermas ornatus"), None);
+    }
+}
@@ -0,0 +1,760 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::Config;
+use crate::data::{Category, Gender, Morpheme, Morphemes, Origin, Root, SpeciesDescriptor, Suffix};
+use crate::generator::{assemble_genus, assemble_genus_joined, get_safe_suffixes, joins_as_vowel};
+use crate::quality::{self, Rejection};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NameIndices {
+    pub prefix: usize,
+    pub root: usize,
+    pub suffix: usize,
+    pub descriptor: usize,
+    pub subspecies: Option<usize>,
+}
+
+/// Every binomial the morpheme tables can produce, numbered `0..size()`.
+///
+/// Names are laid out prefix by prefix. Within a prefix block the root is
+/// the most significant digit and the descriptor the least; the descriptor
+/// radix differs between blocks because each prefix only draws from the
+/// descriptors that suit its category.
+///
+/// A trinomial space adds a subspecies epithet as the least significant
+/// digit of every name. With agreement, epithets are inflected to the gender
+/// of the genus suffix; that changes spellings but not the numbering.
+///
+/// When origins are matched, a root only takes the suffixes that agree with
+/// it, and in a pure space the roots and suffixes must also agree with the
+/// prefix; the root and suffix digits then count the pairs allowed, in the
+/// same root-major order.
+///
+/// Categories may be weighted, in which case each prefix keeps only a
+/// share of its block, spread evenly through it, and its digits count the
+/// names kept.
+///
+/// Repeated spellings are left out so that distinct numbers always spell
+/// distinct names. Indices refer to positions in the space, not in the
+/// underlying tables.
+pub struct NameSpace<'a> {
+    morphemes: &'a Morphemes<'a>,
+    roots: Vec<&'a Root<'a>>,
+    suffixes: Vec<Suffix<'a>>,
+    genera: Vec<Vec<(usize, usize)>>,
+    prefix_genera: Vec<usize>,
+    match_origins: bool,
+    descriptors: Vec<Vec<&'a SpeciesDescriptor<'a>>>,
+    prefix_descriptors: Vec<usize>,
+    subspecies: Vec<&'a SpeciesDescriptor<'a>>,
+    agreement: bool,
+    /// Names of each prefix that its category keeps, all unless weighted.
+    kept: Vec<u64>,
+    offsets: Vec<u64>,
+}
+
+impl<'a> NameSpace<'a> {
+    pub fn new(morphemes: &'a Morphemes<'a>) -> Self {
+        Self::with_origins(morphemes, false, false)
+    }
+
+    pub fn with_origins(morphemes: &'a Morphemes<'a>, match_origins: bool, pure: bool) -> Self {
+        let roots = distinct_roots(&morphemes.roots);
+        let suffixes = get_safe_suffixes(morphemes);
+
+        let mut origins = Vec::new();
+        let mut genera = Vec::new();
+        let mut prefix_genera = Vec::with_capacity(morphemes.prefixes.len());
+
+        for prefix in &morphemes.prefixes {
+            let origin = pure.then_some(prefix.origin);
+            let list = match origins.iter().position(|&o| o == origin) {
+                Some(list) => list,
+                None => {
+                    origins.push(origin);
+                    genera.push(joinable(&roots, &suffixes, origin, match_origins || pure));
+                    genera.len() - 1
+                }
+            };
+            prefix_genera.push(list);
+        }
+
+        let mut categories = Vec::new();
+        let mut descriptors: Vec<Vec<&'a SpeciesDescriptor<'a>>> = Vec::new();
+        let mut prefix_descriptors = Vec::with_capacity(morphemes.prefixes.len());
+
+        for prefix in &morphemes.prefixes {
+            let list = match categories.iter().position(|&c| c == prefix.category) {
+                Some(list) => list,
+                None => {
+                    let suitable = morphemes
+                        .species_descriptors
+                        .iter()
+                        .filter(|d| d.category.is_none() || d.category == Some(prefix.category));
+                    categories.push(prefix.category);
+                    descriptors.push(distinct_descriptors(suitable));
+                    descriptors.len() - 1
+                }
+            };
+            prefix_descriptors.push(list);
+        }
+
+        let kept: Vec<u64> = prefix_descriptors
+            .iter()
+            .zip(&prefix_genera)
+            .map(|(&list, &pairs)| (genera[pairs].len() * descriptors[list].len()) as u64)
+            .collect();
+
+        Self {
+            morphemes,
+            roots,
+            suffixes,
+            genera,
+            prefix_genera,
+            match_origins,
+            descriptors,
+            prefix_descriptors,
+            subspecies: Vec::new(),
+            agreement: false,
+            offsets: offsets(&kept),
+            kept,
+        }
+    }
+
+    pub fn trinomial(morphemes: &'a Morphemes<'a>) -> Self {
+        Self::new(morphemes).with_subspecies()
+    }
+
+    fn with_subspecies(self) -> Self {
+        Self {
+            subspecies: distinct_descriptors(&self.morphemes.subspecies_descriptors),
+            ..self
+        }
+    }
+
+    pub fn with_agreement(self, agreement: bool) -> Self {
+        Self { agreement, ..self }
+    }
+
+    /// Keeps a share of the names of each prefix in proportion to the
+    /// weight of its category over the largest, spread evenly through the
+    /// prefix's block.
+    pub fn with_categories(self, weights: Option<[u32; Category::ALL.len()]>) -> Self {
+        let Some(weights) = weights else {
+            return self;
+        };
+        let max = weights.iter().copied().max().unwrap_or(0).max(1) as u64;
+
+        let kept: Vec<u64> = self
+            .morphemes
+            .prefixes
+            .iter()
+            .enumerate()
+            .map(|(prefix, morpheme)| {
+                let weight = weights[morpheme.category as usize] as u64;
+                (self.block_size(prefix) * weight).div_ceil(max)
+            })
+            .collect();
+
+        Self {
+            offsets: offsets(&kept),
+            kept,
+            ..self
+        }
+    }
+
+    pub fn for_config(morphemes: &'a Morphemes<'a>, config: &Config) -> Self {
+        Self::configured(morphemes, config, config.trinomials)
+    }
+
+    /// The space `name` would come from under `config`, judged by its number
+    /// of words.
+    pub fn for_name(morphemes: &'a Morphemes<'a>, config: &Config, name: &str) -> Self {
+        Self::configured(morphemes, config, name.split_whitespace().count() == 3)
+    }
+
+    pub(crate) fn configured(
+        morphemes: &'a Morphemes<'a>,
+        config: &Config,
+        trinomial: bool,
+    ) -> Self {
+        let space = Self::with_origins(morphemes, config.match_origins, config.pure_origins);
+        let space = if trinomial {
+            space.with_subspecies()
+        } else {
+            space
+        };
+        space
+            .with_agreement(config.gender_agreement)
+            .with_categories(config.categories)
+    }
+
+    pub fn is_trinomial(&self) -> bool {
+        !self.subspecies.is_empty()
+    }
+
+    fn binomial_size(&self) -> u64 {
+        self.offsets[self.offsets.len() - 1]
+    }
+    pub(crate) fn genera_for(&self, prefix: usize) -> &[(usize, usize)] {
+        &self.genera[self.prefix_genera[prefix]]
+    }
+
+    fn descriptors_for(&self, prefix: usize) -> &[&'a SpeciesDescriptor<'a>] {
+        &self.descriptors[self.prefix_descriptors[prefix]]
+    }
+
+    /// Number of names of `prefix` before categories are weighed.
+    fn block_size(&self, prefix: usize) -> u64 {
+        (self.genera_for(prefix).len() * self.descriptors_for(prefix).len()) as u64
+    }
+
+    pub fn size(&self) -> u64 {
+        self.binomial_size() * self.subspecies.len().max(1) as u64
+    }
+
+    pub fn indices(&self, rank: u64) -> NameIndices {
+        if self.is_trinomial() {
+            let count = self.subspecies.len() as u64;
+            return NameIndices {
+                subspecies: Some((rank % count) as usize),
+                ..self.binomial_indices(rank / count)
+            };
+        }
+        self.binomial_indices(rank)
+    }
+
+    fn binomial_indices(&self, rank: u64) -> NameIndices {
+        let prefix = self.offsets.partition_point(|&offset| offset <= rank) - 1;
+        let descriptor_count = self.descriptors_for(prefix).len() as u64;
+
+        let local = spread(
+            rank - self.offsets[prefix],
+            self.kept[prefix],
+            self.block_size(prefix),
+        );
+        let descriptor = local % descriptor_count;
+        let (root, suffix) = self.genera_for(prefix)[(local / descriptor_count) as usize];
+
+        NameIndices {
+            prefix,
+            root,
+            suffix,
+            descriptor: descriptor as usize,
+            subspecies: None,
+        }
+    }
+
+    pub fn rank(&self, indices: &NameIndices) -> u64 {
+        let binomial = self.binomial_rank(indices);
+        match indices.subspecies {
+            Some(subspecies) => binomial * self.subspecies.len() as u64 + subspecies as u64,
+            None => binomial,
+        }
+    }
+
+    fn binomial_rank(&self, indices: &NameIndices) -> u64 {
+        let prefix = indices.prefix;
+        let local = gather(
+            self.block_index(indices),
+            self.kept[prefix],
+            self.block_size(prefix),
+        );
+        self.offsets[prefix] + local
+    }
+
+    /// Position of the name of `indices` in its prefix's block before
+    /// categories are weighed.
+    fn block_index(&self, indices: &NameIndices) -> u64 {
+        let descriptor_count = self.descriptors_for(indices.prefix).len() as u64;
+        let genus = self
+            .genera_for(indices.prefix)
+            .binary_search(&(indices.root, indices.suffix))
+            .expect("root and suffix are joinable");
+
+        genus as u64 * descriptor_count + indices.descriptor as u64
+    }
+
+    /// Whether the name of `indices` is among those its category keeps.
+    fn is_kept(&self, indices: &NameIndices) -> bool {
+        let index = self.block_index(indices);
+        let kept = self.kept[indices.prefix];
+        let block = self.block_size(indices.prefix);
+
+        let local = gather(index, kept, block);
+        local < kept && spread(local, kept, block) == index
+    }
+
+    pub fn prefix(&self, indices: &NameIndices) -> &'a Morpheme<'a> {
+        &self.morphemes.prefixes[indices.prefix]
+    }
+
+    pub fn root(&self, indices: &NameIndices) -> &'a Root<'a> {
+        self.roots[indices.root]
+    }
+
+    pub fn suffix(&self, indices: &NameIndices) -> Suffix<'a> {
+        self.suffixes[indices.suffix]
+    }
+
+    pub fn descriptor(&self, indices: &NameIndices) -> &'a SpeciesDescriptor<'a> {
+        self.descriptors_for(indices.prefix)[indices.descriptor]
+    }
+
+    pub fn subspecies(&self, indices: &NameIndices) -> Option<&'a SpeciesDescriptor<'a>> {
+        indices
+            .subspecies
+            .map(|subspecies| self.subspecies[subspecies])
+    }
+
+    pub fn genus(&self, indices: &NameIndices) -> String {
+        let prefix = self.prefix(indices);
+        let root = self.root(indices);
+        let suffix = self.suffix(indices);
+
+        if self.match_origins {
+            let origin = root.origin.unwrap_or(prefix.origin);
+            assemble_genus_joined(prefix, root.text, origin, suffix.text)
+        } else {
+            assemble_genus(prefix, root.text, suffix.text)
+        }
+    }
+
+    /// The quality rules the name of `indices` breaks under `config`.
+    pub fn rejections(&self, indices: &NameIndices, config: &Config) -> Vec<Rejection> {
+        let mut rejections = quality::rejections(
+            &self.genus(indices),
+            self.roots[indices.root].text,
+            self.suffixes[indices.suffix].text,
+            config,
+        );
+        rejections.extend(quality::name_rejections(&self.name(indices), config));
+        rejections
+    }
+
+    pub fn is_acceptable(&self, indices: &NameIndices, config: &Config) -> bool {
+        quality::is_acceptable(
+            &self.genus(indices),
+            self.roots[indices.root].text,
+            self.suffixes[indices.suffix].text,
+            config,
+        ) && quality::name_rejections(&self.name(indices), config).is_empty()
+    }
+
+    fn epithet(&self, descriptor: &SpeciesDescriptor, gender: Gender) -> String {
+        if self.agreement {
+            descriptor.declension.inflect(descriptor.text, gender)
+        } else {
+            descriptor.text.to_string()
+        }
+    }
+
+    fn could_be(&self, descriptor: &SpeciesDescriptor, word: &str) -> bool {
+        Gender::ALL
+            .iter()
+            .any(|&gender| self.epithet(descriptor, gender) == word)
+    }
+
+    pub fn name(&self, indices: &NameIndices) -> String {
+        let gender = Gender::of_suffix(self.suffixes[indices.suffix].text);
+        let descriptor = self.descriptors_for(indices.prefix)[indices.descriptor];
+        let binomial = format!(
+            "{} {}",
+            self.genus(indices),
+            self.epithet(descriptor, gender)
+        );
+
+        match indices.subspecies {
+            Some(subspecies) => format!(
+                "{} {}",
+                binomial,
+                self.epithet(self.subspecies[subspecies], gender)
+            ),
+            None => binomial,
+        }
+    }
+
+    /// Lists every set of indices that assembles to `name`. The built-in
+    /// tables never yield more than one, but other tables might.
+    pub fn parse(&self, name: &str) -> Vec<NameIndices> {
+        let words: Vec<&str> = name.split_whitespace().collect();
+        let (genus, epithet, subspecies_matches) = match (&words[..], self.is_trinomial()) {
+            (&[genus, epithet], false) => (genus, epithet, vec![None]),
+            (&[genus, epithet, third], true) => {
+                let matches: Vec<Option<usize>> = (0..self.subspecies.len())
+                    .filter(|&idx| self.could_be(self.subspecies[idx], third))
+                    .map(Some)
+                    .collect();
+                (genus, epithet, matches)
+            }
+            _ => return Vec::new(),
+        };
+
+        let genus_lower = genus.to_lowercase();
+        let mut found = Vec::new();
+
+        for (prefix_idx, prefix) in self.morphemes.prefixes.iter().enumerate() {
+            let prefix_text = prefix.text.trim_end_matches('-').to_lowercase();
+            if !genus_lower.starts_with(&prefix_text) {
+                continue;
+            }
+
+            let descriptor_matches: Vec<usize> = self
+                .descriptors_for(prefix_idx)
+                .iter()
+                .enumerate()
+                .filter(|(_, descriptor)| self.could_be(descriptor, epithet))
+                .map(|(idx, _)| idx)
+                .collect();
+
+            if descriptor_matches.is_empty() || subspecies_matches.is_empty() {
+                continue;
+            }
+
+            for &(root, suffix) in self.genera_for(prefix_idx) {
+                let indices = NameIndices {
+                    prefix: prefix_idx,
+                    root,
+                    suffix,
+                    descriptor: 0,
+                    subspecies: None,
+                };
+
+                if self.genus(&indices) != genus {
+                    continue;
+                }
+
+                for &descriptor in &descriptor_matches {
+                    for &subspecies in &subspecies_matches {
+                        let candidate = NameIndices {
+                            descriptor,
+                            subspecies,
+                            ..indices
+                        };
+                        if self.name(&candidate) == name && self.is_kept(&candidate) {
+                            found.push(candidate);
+                        }
+                    }
+                }
+            }
+        }
+
+        found
+    }
+}
+
+fn offsets(kept: &[u64]) -> Vec<u64> {
+    let mut offsets = Vec::with_capacity(kept.len() + 1);
+    let mut total = 0u64;
+    offsets.push(total);
+    for &count in kept {
+        total += count;
+        offsets.push(total);
+    }
+    offsets
+}
+
+/// Position in a block of `block` names of the `local`th of the `kept`
+/// that a weighted category keeps, spaced evenly.
+fn spread(local: u64, kept: u64, block: u64) -> u64 {
+    (local as u128 * block as u128 / kept as u128) as u64
+}
+
+/// Inverse of [`spread`]: the first kept name at or after `index`.
+fn gather(index: u64, kept: u64, block: u64) -> u64 {
+    (index as u128 * kept as u128).div_ceil(block as u128) as u64
+}
+
+/// The root and suffix pairs a genus may join, root-major. With `agree`,
+/// a root only takes suffixes of its own origin and `origin`, if any, and
+/// only roots of `origin` are used. Morphemes of unknown or shared origin
+/// agree with either.
+fn joinable(
+    roots: &[&Root],
+    suffixes: &[Suffix],
+    origin: Option<Origin>,
+    agree: bool,
+) -> Vec<(usize, usize)> {
+    let agrees = |a: Option<Origin>, b: Option<Origin>| a.is_none() || b.is_none() || a == b;
+    let mut pairs = Vec::new();
+
+    for (r, root) in roots.iter().enumerate() {
+        let of_root = if agree { root.origin } else { None };
+        for (s, suffix) in suffixes.iter().enumerate() {
+            let of_suffix = suffix.origin;
+            if !agree
+                || (agrees(of_root, of_suffix)
+                    && agrees(of_root, origin)
+                    && agrees(of_suffix, origin))
+            {
+                pairs.push((r, s));
+            }
+        }
+    }
+
+    pairs
+}
+
+/// Keeps the first descriptor of each spelling.
+fn distinct_descriptors<'a>(
+    descriptors: impl IntoIterator<Item = &'a SpeciesDescriptor<'a>>,
+) -> Vec<&'a SpeciesDescriptor<'a>> {
+    let mut kept: Vec<&SpeciesDescriptor> = Vec::new();
+    for descriptor in descriptors {
+        if !kept.iter().any(|d| d.text == descriptor.text) {
+            kept.push(descriptor);
+        }
+    }
+    kept
+}
+
+/// Keeps the first of any roots that would spell the same genus: exact
+/// repeats, and roots that only add a final vowel to an earlier one, since
+/// that vowel is elided before a vowel suffix ("caul" and "cauli" both give
+/// "-caulus").
+fn distinct_roots<'a>(roots: &'a [Root<'a>]) -> Vec<&'a Root<'a>> {
+    let mut kept: Vec<&'a Root<'a>> = Vec::new();
+
+    for entry in roots {
+        let root = entry.text;
+        let shadowed = kept.iter().map(|earlier| earlier.text).any(|earlier| {
+            root == earlier
+                || root
+                    .strip_prefix(earlier)
+                    .is_some_and(|rest| rest.len() == 1 && rest.chars().all(joins_as_vowel))
+                || earlier
+                    .strip_prefix(root)
+                    .is_some_and(|rest| rest.len() == 1 && rest.chars().all(joins_as_vowel))
+        });
+
+        if !shadowed {
+            kept.push(entry);
+        }
+    }
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_round_trip() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes);
+
+        for rank in (0..space.size()).step_by(9973) {
+            let indices = space.indices(rank);
+            assert_eq!(space.rank(&indices), rank);
+        }
+        assert_eq!(
+            space.rank(&space.indices(space.size() - 1)),
+            space.size() - 1
+        );
+    }
+
+    #[test]
+    fn test_parse_finds_indices() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes);
+
+        for rank in (0..space.size()).step_by(1_000_003) {
+            let indices = space.indices(rank);
+            let name = space.name(&indices);
+            assert!(
+                space.parse(&name).contains(&indices),
+                "Could not parse {} back to {:?}",
+                name,
+                indices
+            );
+        }
+    }
+
+    #[test]
+    fn test_origins_agree() {
+        let morphemes = Morphemes::new();
+        let matched = NameSpace::with_origins(&morphemes, true, false);
+        let pure = NameSpace::with_origins(&morphemes, true, true);
+        let agrees = |a: Option<Origin>, b| a.is_none() || a == Some(b);
+
+        assert!(pure.size() < matched.size());
+        assert!(matched.size() < NameSpace::new(&morphemes).size());
+
+        for space in [&matched, &pure] {
+            for rank in (0..space.size()).step_by(99_991) {
+                let indices = space.indices(rank);
+                assert_eq!(space.rank(&indices), rank);
+                assert!(space.parse(&space.name(&indices)).contains(&indices));
+
+                let prefix = space.prefix(&indices).origin;
+                let root = space.root(&indices).origin.unwrap();
+                let suffix = space.suffix(&indices).origin;
+                assert!(agrees(suffix, root));
+                if space.genera.len() > 1 {
+                    assert_eq!(root, prefix);
+                    assert!(agrees(suffix, prefix));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_names_are_distinct() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes);
+
+        assert!(!space.roots.iter().any(|root| root.text == "cauli"));
+        assert_eq!(
+            space.roots.len(),
+            space
+                .roots
+                .iter()
+                .map(|root| root.text)
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+
+        for rank in (0..space.size()).step_by(100_003) {
+            let indices = space.indices(rank);
+            assert_eq!(space.parse(&space.name(&indices)), vec![indices]);
+        }
+    }
+
+    #[test]
+    fn test_trinomial_round_trip() {
+        let morphemes = Morphemes::new();
+        let binomial = NameSpace::new(&morphemes);
+        let space = NameSpace::trinomial(&morphemes);
+
+        assert_eq!(space.size(), binomial.size() * 100);
+
+        for rank in (0..space.size()).step_by(100_000_007) {
+            let indices = space.indices(rank);
+            let name = space.name(&indices);
+
+            assert_eq!(name.split_whitespace().count(), 3);
+            assert_eq!(space.rank(&indices), rank);
+            assert_eq!(space.parse(&name), vec![indices]);
+            assert!(binomial.parse(&name).is_empty());
+        }
+
+        assert!(space.parse("Neomorphus lucidus").is_empty());
+        assert!(space.parse("Neomorphus lucidus qqqq").is_empty());
+    }
+
+    #[test]
+    fn test_agreement_round_trip() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::trinomial(&morphemes).with_agreement(true);
+
+        for rank in (0..space.size()).step_by(10_000_019) {
+            let indices = space.indices(rank);
+            assert_eq!(space.parse(&space.name(&indices)), vec![indices]);
+        }
+
+        // Inflection must not make two epithets of one genus look alike.
+        for gender in Gender::ALL {
+            for descriptors in space.descriptors.iter().chain([&space.subspecies]) {
+                let forms: std::collections::HashSet<_> = descriptors
+                    .iter()
+                    .map(|d| d.declension.inflect(d.text, gender))
+                    .collect();
+                assert_eq!(forms.len(), descriptors.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted_categories_round_trip() {
+        let morphemes = Morphemes::new();
+        let full = NameSpace::new(&morphemes);
+        let mut weights = [0; Category::ALL.len()];
+        weights[Category::Colour as usize] = 3;
+        weights[Category::Form as usize] = 1;
+        let space = NameSpace::new(&morphemes).with_categories(Some(weights));
+
+        let count = |space: &NameSpace, category| {
+            (0..morphemes.prefixes.len())
+                .filter(|&p| morphemes.prefixes[p].category == category)
+                .map(|p| space.kept[p])
+                .sum::<u64>()
+        };
+        let colour = count(&space, Category::Colour);
+        let form = count(&space, Category::Form);
+        assert_eq!(colour, count(&full, Category::Colour));
+        assert!(form.abs_diff(count(&full, Category::Form) / 3) < 10);
+        assert_eq!(space.size(), colour + form);
+
+        for rank in (0..space.size()).step_by(10_007) {
+            let indices = space.indices(rank);
+            let name = space.name(&indices);
+            let category = space.prefix(&indices).category;
+
+            assert!(category == Category::Colour || category == Category::Form);
+            assert_eq!(space.rank(&indices), rank);
+            assert_eq!(space.parse(&name), vec![indices]);
+        }
+
+        // A third of the form names are kept: the first of every three.
+        let prefix = morphemes
+            .prefixes
+            .iter()
+            .position(|p| p.category == Category::Form)
+            .unwrap();
+        let (root, suffix) = space.genera_for(prefix)[0];
+        let kept = NameIndices {
+            prefix,
+            root,
+            suffix,
+            descriptor: 0,
+            subspecies: None,
+        };
+        let dropped = NameIndices {
+            descriptor: 1,
+            ..kept
+        };
+        assert_eq!(space.parse(&full.name(&kept)), vec![kept]);
+        assert!(space.parse(&full.name(&dropped)).is_empty());
+        assert!(space.parse("Neomorphus lucidus").is_empty());
+    }
+
+    #[test]
+    fn test_agreement_follows_genus() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes).with_agreement(true);
+        let plain = NameSpace::new(&morphemes);
+
+        for name in ["Cryptonatis rara", "Archaeofemoroma alpinum"] {
+            assert_eq!(space.parse(name).len(), 1, "{}", name);
+            assert!(plain.parse(name).is_empty());
+        }
+        assert!(space.parse("Cryptonatis rarus").is_empty());
+        assert!(space.parse("Trachyotas dubia").is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_names() {
+        let morphemes = Morphemes::new();
+        let space = NameSpace::new(&morphemes);
+
+        assert!(space.parse("Neomorphus").is_empty());
+        assert!(space.parse("Neomorphus lucidus extra").is_empty());
+        assert!(space.parse("Qqqq lucidus").is_empty());
+    }
+}
@@ -0,0 +1,330 @@
+// This file is part of Holotype.
+//
+// Copyright (c) 2026  René Coignard <contact@renecoignard.com>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Golden vectors pinning the names each scheme produces.
+//!
+//! The corpus is compiled into the binary so that `holotype verify` checks
+//! the build it ships with rather than whatever files happen to be nearby.
+
+use crate::config::{self, Overrides};
+use crate::data;
+use crate::key::Key;
+use crate::scheme::{self, Scheme};
+use chrono::{NaiveDate, NaiveTime};
+
+pub const GOLDEN_VECTORS: &str = include_str!("data/vectors.tsv");
+
+#[derive(Clone, Debug)]
+pub struct Vector {
+    pub line: usize,
+    pub scheme: &'static Scheme,
+    /// The theme and name space options the name was generated with.
+    pub options: Overrides,
+    pub date: NaiveDate,
+    pub work: Work,
+    pub salt: String,
+    pub key: Option<Key>,
+    pub name: String,
+}
+
+/// What a vector names on its date: a numbered work or a time slot.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Work {
+    Number(u32),
+    Time(NaiveTime),
+}
+
+#[derive(Default)]
+pub struct Report {
+    pub generated: usize,
+    pub decoded: usize,
+    pub failures: Vec<String>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl Vector {
+    /// The vector's scheme with the options its name was generated with.
+    fn configured_scheme(&self) -> Result<Scheme, String> {
+        // Trinomial vectors are recognised by their third word.
+        let overrides = Overrides {
+            trinomials: self.name.split_whitespace().count() == 3,
+            ..self.options.clone()
+        };
+        self.scheme.with_overrides(&overrides)
+    }
+}
+
+pub fn parse_vectors(text: &str) -> Result<Vec<Vector>, String> {
+    let mut vectors = Vec::new();
+
+    for (idx, line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        let (scheme_id, date, number, salt, name, key) = match fields[..] {
+            [scheme_id, date, number, salt, name] => (scheme_id, date, number, salt, name, None),
+            [scheme_id, date, number, salt, name, key] => {
+                (scheme_id, date, number, salt, name, Some(key))
+            }
+            _ => {
+                return Err(format!(
+                    "Line {}: expected 5 or 6 tab-separated fields, found {}",
+                    line_no,
+                    fields.len()
+                ))
+            }
+        };
+
+        let key = key
+            .map(Key::from_hex)
+            .transpose()
+            .map_err(|e| format!("Line {}: {}", line_no, e))?;
+        let mut options = scheme_id.split('+');
+        let scheme_id = options.next().unwrap_or_default();
+        let options = parse_options(options).map_err(|e| format!("Line {}: {}", line_no, e))?;
+        let (scheme_id, theme) = match scheme_id.split_once('/') {
+            Some((scheme_id, theme)) => (scheme_id, Some(theme)),
+            None => (scheme_id, None),
+        };
+        let scheme = scheme::find(scheme_id)
+            .ok_or_else(|| format!("Line {}: unknown scheme: {}", line_no, scheme_id))?;
+        let theme = theme
+            .map(|id| {
+                data::find_theme(id)
+                    .ok_or_else(|| format!("Line {}: unknown theme: {}", line_no, id))
+            })
+            .transpose()?;
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Line {}: invalid date: {}", line_no, date))?;
+        let work = if number.contains(':') {
+            NaiveTime::parse_from_str(number, "%H:%M")
+                .map(Work::Time)
+                .map_err(|_| format!("Line {}: invalid time: {}", line_no, number))?
+        } else {
+            number
+                .parse::<u32>()
+                .map(Work::Number)
+                .map_err(|_| format!("Line {}: invalid number: {}", line_no, number))?
+        };
+
+        vectors.push(Vector {
+            line: line_no,
+            scheme,
+            options: Overrides { theme, ..options },
+            date,
+            work,
+            salt: salt.to_string(),
+            key,
+            name: name.to_string(),
+        });
+    }
+
+    Ok(vectors)
+}
+
+/// Parses the options after a vector's scheme: `pure` and
+/// `categories=LIST`, as on the command line.
+fn parse_options<'a>(options: impl Iterator<Item = &'a str>) -> Result<Overrides, String> {
+    let mut overrides = Overrides::default();
+
+    for option in options {
+        match option.split_once('=') {
+            None if option == "pure" => overrides.pure_origins = true,
+            Some(("categories", list)) => {
+                overrides.categories = Some(config::parse_categories(list)?)
+            }
+            _ => return Err(format!("unknown option: {}", option)),
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Regenerates every vector and, for schemes that decode instantly, checks
+/// that decoding the name leads back to it. Legacy names are only generated:
+/// looking one up regenerates the whole century.
+pub fn verify(vectors: &[Vector]) -> Report {
+    let mut report = Report::default();
+
+    for vector in vectors {
+        let scheme = match vector.configured_scheme() {
+            Ok(scheme) => scheme,
+            Err(e) => {
+                report.failures.push(format!("Line {}: {}", vector.line, e));
+                continue;
+            }
+        };
+        let morphemes = scheme.morphemes();
+        let config = &scheme.config;
+
+        let key = vector.key.as_ref();
+        let (generated, tuple) = match vector.work {
+            Work::Number(number) => (
+                scheme.generate_name(vector.date, number, &vector.salt, key, &morphemes, config),
+                format!("{} #{}", vector.date, number),
+            ),
+            Work::Time(time) => (
+                scheme.generate_timed_name(
+                    vector.date.and_time(time),
+                    &vector.salt,
+                    key,
+                    &morphemes,
+                    config,
+                ),
+                format!("{} {}", vector.date, time.format("%H:%M")),
+            ),
+        };
+
+        let name = match generated {
+            Ok(name) => name,
+            Err(e) => {
+                report.failures.push(format!("Line {}: {}", vector.line, e));
+                continue;
+            }
+        };
+        report.generated += 1;
+
+        if name != vector.name {
+            report.failures.push(format!(
+                "Line {}: {} {} generated {:?}, expected {:?}",
+                vector.line, scheme.id, tuple, name, vector.name
+            ));
+            continue;
+        }
+
+        if !scheme.decodes_instantly() {
+            continue;
+        }
+
+        let found = match vector.work {
+            Work::Number(number) => scheme
+                .decode_candidates(&vector.name, &vector.salt, key, &morphemes, config)
+                .is_ok_and(|candidates| candidates.contains(&(vector.date, number))),
+            Work::Time(time) => scheme
+                .decode_timed_candidates(&vector.name, &vector.salt, key, &morphemes, config)
+                .is_ok_and(|candidates| candidates.contains(&vector.date.and_time(time))),
+        };
+        report.decoded += 1;
+
+        if !found {
+            report.failures.push(format!(
+                "Line {}: {} {:?} did not decode to {}",
+                vector.line, scheme.id, vector.name, tuple
+            ));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Layout;
+
+    #[test]
+    fn test_corpus_covers_every_scheme() {
+        let vectors = parse_vectors(GOLDEN_VECTORS).unwrap();
+
+        for id in scheme::ids() {
+            assert!(
+                vectors.iter().any(|v| v.scheme.id == id),
+                "No golden vectors for scheme {}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_numbered_and_timed_vectors_stay_apart() {
+        // Schemes with a strided layout give moments a lane of their own, so
+        // no name may stand for both a numbered work and a moment.
+        let vectors = parse_vectors(GOLDEN_VECTORS).unwrap();
+        let mut checked = 0;
+
+        for vector in vectors
+            .iter()
+            .filter(|v| matches!(v.scheme.config.layout, Layout::Strided { .. }))
+        {
+            let scheme = vector.configured_scheme().unwrap();
+            let morphemes = scheme.morphemes();
+            let (name, salt, key) = (&vector.name, &vector.salt, vector.key.as_ref());
+
+            match vector.work {
+                Work::Number(_) => assert_eq!(
+                    scheme.decode_timed_candidates(name, salt, key, &morphemes, &scheme.config),
+                    Ok(Vec::new()),
+                    "{} also decodes as a moment",
+                    name
+                ),
+                Work::Time(_) => assert_eq!(
+                    scheme.decode_candidates(name, salt, key, &morphemes, &scheme.config),
+                    Ok(Vec::new()),
+                    "{} also decodes as a numbered work",
+                    name
+                ),
+            }
+            checked += 1;
+        }
+
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_lines() {
+        assert!(parse_vectors("# comment\n\n").unwrap().is_empty());
+        assert!(parse_vectors("v2\t2026-01-04\t3\t").is_err());
+        assert!(parse_vectors("v10\t2026-01-04\t3\t\tName x").is_err());
+        assert!(parse_vectors("v7/lunar\t2026-01-04\t3\t\tName x").is_err());
+        assert!(parse_vectors("v9+loud\t2026-01-04\t3\t\tName x").is_err());
+        assert!(parse_vectors("v9+categories=mood\t2026-01-04\t3\t\tName x").is_err());
+
+        let vectors =
+            parse_vectors("v9/marine+pure+categories=size=2,form\t2026-01-04\t3\t\tName x")
+                .unwrap();
+        let options = &vectors[0].options;
+        assert_eq!(options.theme.map(|theme| theme.id), Some("marine"));
+        assert!(options.pure_origins);
+        assert_eq!(
+            options.categories,
+            Some(config::parse_categories("form,size=2").unwrap())
+        );
+        assert!(parse_vectors("v2\t2026-13-04\t3\t\tName x").is_err());
+        assert!(parse_vectors("v2\t2026-01-04\tthree\t\tName x").is_err());
+        assert!(parse_vectors("v2\t2026-01-04\t25:00\t\tName x").is_err());
+        assert!(parse_vectors("v2\t2026-01-04\t3\t\tName x\t0001").is_err());
+        assert!(parse_vectors("v2\t2026-01-04\t3\t\tName x\t\t").is_err());
+    }
+
+    #[test]
+    fn test_verify_reports_mismatches() {
+        let vectors = parse_vectors("v2\t2026-01-04\t3\t\tTrachyotas vulgaris").unwrap();
+        let report = verify(&vectors);
+
+        assert!(!report.is_ok());
+        assert_eq!(report.failures.len(), 1);
+        assert!(report.failures[0].contains("Trachyotas dubius"));
+    }
+}
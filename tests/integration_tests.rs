@@ -1,8 +1,35 @@
 use chrono::NaiveDate;
-use holotype::config::Config;
-use holotype::data::Morphemes;
+use holotype::config::{Config, Overrides};
+use holotype::data::{Category, Morphemes};
 use holotype::generator::{decode_name, generate_name};
-use holotype::pronounceability::pronounceability_score;
+use holotype::quality::Rejection;
+use holotype::scheme::Scheme;
+use holotype::verify::{parse_vectors, verify, GOLDEN_VECTORS};
+use std::sync::Arc;
+
+/// The newest scheme with `overrides` applied.
+fn latest_with(overrides: &Overrides) -> Scheme {
+    holotype::scheme::latest()
+        .with_overrides(overrides)
+        .unwrap()
+}
+
+/// Asserts that `name` decodes to the date and number alone.
+fn assert_decodes(
+    name: &str,
+    salt: &str,
+    morphemes: &Morphemes,
+    config: &Config,
+    date: NaiveDate,
+    number: u32,
+) {
+    assert_eq!(
+        holotype::generator::decode_candidates(name, salt, morphemes, config),
+        vec![(date, number)],
+        "{}",
+        name
+    );
+}
 
 #[test]
 fn test_full_cycle() {
@@ -13,7 +40,7 @@ fn test_full_cycle() {
     let number = 42;
     let salt = "test_salt";
 
-    let name = generate_name(date, number, salt, &morphemes, &config);
+    let name = generate_name(date, number, salt, &morphemes, &config).unwrap();
     let decoded = decode_name(&name, salt, &morphemes, &config);
 
     assert_eq!(decoded, Some((date, number)));
@@ -29,7 +56,7 @@ fn test_bijectivity_comprehensive() {
     for (year, month, day) in test_dates {
         let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
         for num in [1, 10, 50, 99] {
-            let name = generate_name(date, num, "", &morphemes, &config);
+            let name = generate_name(date, num, "", &morphemes, &config).unwrap();
             let decoded = decode_name(&name, "", &morphemes, &config);
 
             assert_eq!(
@@ -59,7 +86,7 @@ fn test_no_collisions() {
             for day in [1, 15] {
                 if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
                     for num in 1..=10 {
-                        let name = generate_name(date, num, "", &morphemes, &config);
+                        let name = generate_name(date, num, "", &morphemes, &config).unwrap();
                         assert!(
                             seen_names.insert(name.clone()),
                             "Collision found: {} generated for multiple (date, number) pairs",
@@ -78,9 +105,9 @@ fn test_salt_independence() {
     let config = Config::default();
     let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
 
-    let name1 = generate_name(date, 1, "", &morphemes, &config);
-    let name2 = generate_name(date, 1, "salt1", &morphemes, &config);
-    let name3 = generate_name(date, 1, "salt2", &morphemes, &config);
+    let name1 = generate_name(date, 1, "", &morphemes, &config).unwrap();
+    let name2 = generate_name(date, 1, "salt1", &morphemes, &config).unwrap();
+    let name3 = generate_name(date, 1, "salt2", &morphemes, &config).unwrap();
 
     assert_ne!(name1, name2);
     assert_ne!(name2, name3);
@@ -114,8 +141,9 @@ fn test_pronounceability_threshold() {
         for month in 1..=12 {
             if let Some(date) = NaiveDate::from_ymd_opt(year, month, 1) {
                 for num in 1..=20 {
-                    let name = generate_name(date, num, "", &morphemes, &config);
-                    let score = pronounceability_score(&name);
+                    let name = generate_name(date, num, "", &morphemes, &config).unwrap();
+                    let genus = name.split_whitespace().next().unwrap();
+                    let score = config.pronounceability.score(genus);
 
                     assert!(
                         score >= config.min_pronounceability_score,
@@ -137,7 +165,7 @@ fn test_genus_length_limit() {
 
     for num in 1..=50 {
         let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
-        let name = generate_name(date, num, "", &morphemes, &config);
+        let name = generate_name(date, num, "", &morphemes, &config).unwrap();
         let genus = name.split_whitespace().next().unwrap();
 
         assert!(
@@ -162,4 +190,482 @@ fn test_config_validation() {
     config = Config::default();
     config.min_pronounceability_score = 1.5;
     assert!(config.validate().is_err());
+
+    config = Config::default();
+    config.layout = holotype::config::Layout::Strided { attempts: 1 };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_golden_vectors() {
+    let vectors = parse_vectors(GOLDEN_VECTORS).unwrap();
+    let report = verify(&vectors);
+
+    assert!(report.is_ok(), "{}", report.failures.join("\n"));
+    assert_eq!(report.generated, vectors.len());
+}
+
+#[test]
+fn test_large_counters() {
+    let overrides = Overrides::parse(Some("2020-2026"), None, Some("1-9999")).unwrap();
+    let scheme = latest_with(&overrides);
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    assert!(holotype::generator::check_capacity(&morphemes, config).is_ok());
+
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    for number in [1, 99, 100, 350, 9999] {
+        let name = generate_name(date, number, "", &morphemes, config).unwrap();
+        assert_decodes(&name, "", &morphemes, config, date, number);
+    }
+}
+
+#[test]
+fn test_trained_model() {
+    let words = holotype::ngram::read_words(holotype::ngram::BUILTIN_WORDS);
+    let model = holotype::ngram::NgramModel::train(&words[..50], 2).unwrap();
+    let overrides = Overrides {
+        model: Some(Arc::new(model)),
+        ..Overrides::default()
+    };
+    let scheme = latest_with(&overrides);
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    for number in [1, 13, 42, 99] {
+        let name = generate_name(date, number, "", &morphemes, config).unwrap();
+        let genus = name.split_whitespace().next().unwrap();
+        assert!(config.pronounceability.score(genus) >= config.min_pronounceability_score);
+        assert_decodes(&name, "", &morphemes, config, date, number);
+    }
+    assert!(holotype::scheme::find("v1")
+        .unwrap()
+        .with_overrides(&overrides)
+        .is_err());
+}
+
+#[test]
+fn test_user_blocklist() {
+    let scheme = holotype::scheme::latest();
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    let plain = generate_name(date, 7, "", &scheme.morphemes(), &scheme.config).unwrap();
+    let epithet = plain.split_whitespace().nth(1).unwrap();
+
+    // Blocking the epithet sends the tuple on to a later try, which still
+    // decodes back to it.
+    let blocklist = holotype::blocklist::Blocklist::new(&[epithet]);
+    let overrides = Overrides {
+        blocklist: Some(Arc::new(blocklist)),
+        ..Overrides::default()
+    };
+    let scheme = scheme.with_overrides(&overrides).unwrap();
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+
+    let name = generate_name(date, 7, "", &morphemes, config).unwrap();
+    assert!(!name.split_whitespace().any(|word| word == epithet));
+    assert!(holotype::quality::name_rejections(&name, config).is_empty());
+    assert_decodes(&name, "", &morphemes, config, date, 7);
+
+    let rejected =
+        holotype::generator::rejected_names(date, 7, "", None, &morphemes, config).unwrap();
+    let (_, why) = rejected.iter().find(|(name, _)| *name == plain).unwrap();
+    assert!(why.contains(&Rejection::Blocked {
+        word: epithet.to_string(),
+        entry: epithet.to_string(),
+    }));
+}
+
+#[test]
+fn test_builtin_blocklist() {
+    let scheme = holotype::scheme::latest();
+    let (morphemes, config) = (scheme.morphemes(), &scheme.config);
+    let date = NaiveDate::from_ymd_opt(2026, 9, 29).unwrap();
+
+    // Built-in blocked words are rare, but the first try of this tuple
+    // spells one, and a later try names it.
+    let rejected =
+        holotype::generator::rejected_names(date, 68, "", None, &morphemes, config).unwrap();
+    assert_eq!(
+        rejected[0],
+        (
+            "Analingen admirabile".to_string(),
+            vec![Rejection::Blocked {
+                word: "Analingen".to_string(),
+                entry: "analing".to_string(),
+            }]
+        )
+    );
+
+    let name = generate_name(date, 68, "", &morphemes, config).unwrap();
+    assert!(!name.to_lowercase().contains("analing"));
+    assert_decodes(&name, "", &morphemes, config, date, 68);
+}
+
+#[test]
+fn test_exhausted_retries_are_refused() {
+    // With every vowel blocked no try can pass, and the tuple must get an
+    // error rather than one of the names the filter turned down.
+    let blocklist = holotype::blocklist::Blocklist::new(&["a", "e", "i", "o", "u"]);
+    let overrides = Overrides {
+        blocklist: Some(Arc::new(blocklist)),
+        ..Overrides::default()
+    };
+    let scheme = latest_with(&overrides);
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+    assert!(generate_name(date, 7, "", &morphemes, config).is_err());
+    let rejected =
+        holotype::generator::rejected_names(date, 7, "", None, &morphemes, config).unwrap();
+    assert!(rejected.len() > 1);
+    assert!(rejected
+        .iter()
+        .all(|(_, why)| why.iter().any(|r| matches!(r, Rejection::Blocked { .. }))));
+
+    let moment = date.and_hms_opt(14, 36, 0).unwrap();
+    assert!(
+        holotype::generator::generate_timed_name(moment, "", None, &morphemes, config).is_err()
+    );
+}
+
+#[test]
+fn test_distinct_names_decode() {
+    use clap::Parser;
+
+    let scheme = holotype::scheme::latest();
+    let (morphemes, config) = (scheme.morphemes(), &scheme.config);
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    let plain = generate_name(date, 13, "", &morphemes, config).unwrap();
+    let next = generate_name(date, 14, "", &morphemes, config).unwrap();
+
+    // A name issued earlier that the tuple's own could be mistaken for
+    // leaves the number unnamed and moves on to the next.
+    let mut issued = plain.clone();
+    issued.pop();
+    let path = std::env::temp_dir().join(format!("holotype-ledger-{}.txt", std::process::id()));
+    std::fs::write(&path, format!("{}\n", issued)).unwrap();
+
+    let args = ["holotype", "13", "--date", "2026-01-04", "--distinct"];
+    let cli =
+        holotype::cli::Cli::try_parse_from(args.iter().copied().chain([path.to_str().unwrap()]))
+            .unwrap();
+    holotype::run(cli).unwrap();
+    let ledger = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        ledger,
+        format!(
+            "{}\n# skipped {}, too close to {}\n{}\n",
+            issued, plain, issued, next
+        )
+    );
+    assert!(holotype::similarity::is_similar(&plain, &issued));
+    assert!(!holotype::similarity::is_similar(&next, &issued));
+    assert_decodes(&next, "", &morphemes, config, date, 14);
+}
+
+#[test]
+fn test_morpheme_pack() {
+    let mut roots: Vec<&str> = Vec::new();
+    for root in &holotype::data::ROOTS[..150] {
+        if !roots.contains(&root.text) {
+            roots.push(root.text);
+        }
+    }
+    let pack = format!(
+        r#"
+roots = {:?}
+genus_suffixes = ["us", "a", "um"]
+
+[[prefixes]]
+text = "Macro"
+origin = "greek"
+category = "size"
+
+[[prefixes]]
+text = "Rubri"
+origin = "latin"
+category = "colour"
+
+[[species_descriptors]]
+text = "robustus"
+category = "size"
+declension = "us-a-um"
+
+[[species_descriptors]]
+text = "ruber"
+category = "colour"
+declension = "er-ra-rum"
+
+[[species_descriptors]]
+text = "velox"
+
+[[species_descriptors]]
+text = "elegans"
+
+[[subspecies_descriptors]]
+text = "creticus"
+declension = "us-a-um"
+
+[[subspecies_descriptors]]
+text = "alpinus"
+declension = "us-a-um"
+
+[[subspecies_descriptors]]
+text = "orientalis"
+
+[[subspecies_descriptors]]
+text = "simplex"
+"#,
+        roots
+    );
+    let pack = holotype::data::Pack::parse(&pack, false).unwrap();
+    let overrides = Overrides {
+        pack: Some(Arc::new(pack)),
+        ..Overrides::parse(Some("2026-2027"), None, Some("1-2")).unwrap()
+    };
+    let scheme = latest_with(&overrides);
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    holotype::generator::check_capacity(&morphemes, config).unwrap();
+
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    for number in [1, 2] {
+        let name = generate_name(date, number, "", &morphemes, config).unwrap();
+        assert!(name.starts_with("Macro") || name.starts_with("Rubri"));
+        assert_decodes(&name, "", &morphemes, config, date, number);
+    }
+}
+
+#[test]
+fn test_themed_names_decode() {
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+    for theme in holotype::data::THEMES {
+        let overrides = Overrides {
+            theme: Some(theme),
+            ..Overrides::default()
+        };
+        let scheme = latest_with(&overrides);
+        let morphemes = scheme.morphemes();
+        let name = generate_name(date, 1, "", &morphemes, &scheme.config).unwrap();
+
+        let candidates = holotype::decoder::decode_any(&name, "", None, &Overrides::default());
+        assert!(candidates
+            .iter()
+            .any(|c| c.date == date && c.number == Some(1) && c.theme == Some(theme.id)));
+    }
+}
+
+#[test]
+fn test_themes_per_type() {
+    let parse_themes = holotype::config::parse_themes;
+    let theme_id = |text, salt| parse_themes(text, salt).unwrap().map(|theme| theme.id);
+
+    assert_eq!(theme_id("marine", "Beetle"), Some("marine"));
+    assert_eq!(
+        theme_id("Beetle=mineral,Song=acoustic", "Beetle"),
+        Some("mineral")
+    );
+    assert_eq!(
+        theme_id("Beetle=mineral,Song=acoustic", "Song"),
+        Some("acoustic")
+    );
+    assert_eq!(theme_id("Beetle=mineral,Song=acoustic", ""), None);
+
+    assert!(parse_themes("Beetle=mineral,Song=coral", "Beetle").is_err());
+    assert!(parse_themes("Beetle=mineral,Beetle=marine", "Song").is_err());
+    assert!(parse_themes("Beetle=mineral,marine", "Beetle").is_err());
+}
+
+#[test]
+fn test_pure_names_decode() {
+    let overrides = Overrides {
+        pure_origins: true,
+        ..Overrides::default()
+    };
+    let scheme = latest_with(&overrides);
+    let morphemes = scheme.morphemes();
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+    for number in [1, 50, 99] {
+        let name = generate_name(date, number, "", &morphemes, &scheme.config).unwrap();
+        let candidates = holotype::decoder::decode_any(&name, "", None, &overrides);
+        assert!(candidates
+            .iter()
+            .any(|c| c.date == date && c.number == Some(number)));
+    }
+}
+
+#[test]
+fn test_category_names_decode() {
+    let overrides = Overrides {
+        categories: Some(holotype::config::parse_categories("environment=2,colour").unwrap()),
+        ..Overrides::parse(Some("2020-2029"), None, None).unwrap()
+    };
+    let scheme = latest_with(&overrides);
+    let morphemes = scheme.morphemes();
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+    for number in [1, 50, 99] {
+        let name = generate_name(date, number, "ambient", &morphemes, &scheme.config).unwrap();
+        let genus = name.split_whitespace().next().unwrap();
+        assert!(morphemes.prefixes.iter().any(|p| genus.starts_with(p.text)
+            && matches!(p.category, Category::Environment | Category::Colour)));
+
+        let candidates = holotype::decoder::decode_any(&name, "ambient", None, &overrides);
+        assert!(candidates
+            .iter()
+            .any(|c| c.date == date && c.number == Some(number)));
+    }
+
+    // Up to v8 two categories leave too little room to retry every pair of
+    // the default ranges, and v9 has too little for their moments.
+    let overrides = Overrides {
+        categories: Some(holotype::config::parse_categories("colour,environment").unwrap()),
+        ..Overrides::default()
+    };
+    let v8 = holotype::scheme::find("v8")
+        .unwrap()
+        .with_overrides(&overrides)
+        .unwrap();
+    assert!(holotype::generator::check_capacity(&v8.morphemes(), &v8.config).is_err());
+    let v9 = latest_with(&overrides);
+    assert!(holotype::generator::check_capacity(&v9.morphemes(), &v9.config).is_ok());
+    assert!(holotype::generator::check_time_capacity(&v9.morphemes(), &v9.config).is_err());
+
+    assert!(holotype::config::parse_categories("colour,mood").is_err());
+    assert!(holotype::config::parse_categories("colour,colour=2").is_err());
+    assert!(holotype::config::parse_categories("colour=x").is_err());
+}
+
+#[test]
+fn test_categories_per_type() {
+    let text = "ambient=environment:2,colour;drums=form,size";
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+
+    for (salt, kept) in [
+        ("ambient", [Category::Environment, Category::Colour]),
+        ("drums", [Category::Form, Category::Size]),
+    ] {
+        let categories = holotype::config::parse_categories_for(text, salt).unwrap();
+        let overrides = Overrides {
+            categories,
+            ..Overrides::parse(Some("2020-2029"), None, None).unwrap()
+        };
+        let scheme = latest_with(&overrides);
+        let morphemes = scheme.morphemes();
+
+        for number in [1, 50, 99] {
+            let name = generate_name(date, number, salt, &morphemes, &scheme.config).unwrap();
+            let genus = name.split_whitespace().next().unwrap();
+            assert!(morphemes
+                .prefixes
+                .iter()
+                .any(|p| genus.starts_with(p.text) && kept.contains(&p.category)));
+
+            let candidates = holotype::decoder::decode_any(&name, salt, None, &overrides);
+            assert!(candidates
+                .iter()
+                .any(|c| c.date == date && c.number == Some(number)));
+        }
+    }
+
+    let parse = holotype::config::parse_categories_for;
+    assert_eq!(parse(text, "vocals").unwrap(), None);
+    assert_eq!(
+        parse("colour=2,form", "drums").unwrap(),
+        Some(holotype::config::parse_categories("colour:2,form").unwrap())
+    );
+    assert!(parse("drums=form;drums=size", "drums").is_err());
+    assert!(parse("drums=form;size", "drums").is_err());
+    assert!(parse("drums=mood", "drums").is_err());
+}
+
+#[test]
+fn test_default_decode_prefers_numbered_works() {
+    let scheme = holotype::scheme::latest();
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    let name = generate_name(date, 3, "", &scheme.morphemes(), &scheme.config).unwrap();
+
+    // The name also reads as a moment in older schemes, by chance.
+    let v7 = holotype::scheme::find("v7").unwrap();
+    assert!(!holotype::decoder::decode(&name, "", None, v7)
+        .unwrap()
+        .iter()
+        .all(|c| c.number.is_some()));
+
+    let candidates = holotype::decoder::decode_any(&name, "", None, &Overrides::default());
+    assert_eq!(candidates[0].number, Some(3));
+    assert!(candidates.iter().all(|c| c.number.is_some()));
+    assert!(!holotype::decoder::needs_legacy(&candidates, None));
+
+    // A v1 name matches no later scheme, so v1 is next.
+    let candidates =
+        holotype::decoder::decode_any("Anisoorche riparius", "", None, &Overrides::default());
+    assert!(holotype::decoder::needs_legacy(&candidates, None));
+}
+
+#[test]
+fn test_default_numbers_reach_999() {
+    let scheme = holotype::scheme::latest();
+    let morphemes = scheme.morphemes();
+    let config = &scheme.config;
+    let wider = Overrides::parse(None, None, Some("1-9999")).unwrap();
+    let wider = scheme.with_overrides(&wider).unwrap();
+
+    let date = NaiveDate::from_ymd_opt(2026, 1, 4).unwrap();
+    for number in [350, 999] {
+        let name = generate_name(date, number, "", &morphemes, config).unwrap();
+        assert_decodes(&name, "", &morphemes, config, date, number);
+    }
+
+    // A wider range only appends blocks of numbers.
+    assert_eq!(
+        generate_name(date, 350, "", &morphemes, &wider.config).unwrap(),
+        generate_name(date, 350, "", &morphemes, config).unwrap()
+    );
+}
+
+#[test]
+fn test_command_line() {
+    use clap::{CommandFactory, Parser};
+    use holotype::cli::{Cli, Command};
+
+    Cli::command().debug_assert();
+
+    let cli = Cli::try_parse_from(["holotype", "5", "--date", "2026-01-04"]).unwrap();
+    assert!(cli.command.is_none());
+    assert_eq!(cli.value.as_deref(), Some("5"));
+
+    let cli = Cli::try_parse_from(["holotype", "-x", "Isoopticas horridus"]).unwrap();
+    assert!(cli.extract && cli.command.is_none());
+
+    let cli = Cli::try_parse_from(["holotype", "generate", "5", "--type", "patch"]).unwrap();
+    match cli.command {
+        Some(Command::Generate(args)) => {
+            assert_eq!(args.number, Some(5));
+            assert_eq!(args.names.salt.as_deref(), Some("patch"));
+        }
+        _ => panic!("expected generate"),
+    }
+
+    let cli = Cli::try_parse_from(["holotype", "batch", "1-12", "--days", "7"]).unwrap();
+    assert!(matches!(cli.command, Some(Command::Batch(args)) if args.days == 7));
+
+    let cli = Cli::try_parse_from(["holotype", "explain", "Name", "--theme", "marine"]).unwrap();
+    assert!(
+        matches!(cli.command, Some(Command::Explain(args)) if args.names.theme.as_deref() == Some("marine"))
+    );
+
+    let cli = Cli::try_parse_from(["holotype", "batch", "5-3"]).unwrap();
+    assert!(holotype::run(cli).is_err());
+
+    assert!(Cli::try_parse_from(["holotype", "decode"]).is_err());
+    assert!(Cli::try_parse_from(["holotype", "generate", "5", "--now"]).is_err());
+    assert!(Cli::try_parse_from(["holotype", "generate", "--index", "5"]).is_err());
 }